@@ -4,6 +4,20 @@ use {
 };
 
 impl Root {
+    /// Returns the prefix that's needed to be prepended in order to conjugate this root
+    ///
+    /// This is only non-empty for honorific and humble forms (お～になる, ご～する, etc.)
+    pub fn conjugation_prefix(&self) -> &'static str {
+        let honorific = self
+            .steps
+            .iter()
+            .any(|step| matches!(step, Step::ONiNaru | Step::OSuru | Step::OKudasai));
+        match self.kind {
+            _ if !honorific => "",
+            RootKind::Suru | RootKind::SpecialSuru => "ご",
+            _ => "お",
+        }
+    }
     /// Returns the suffix that's needed to be appended in order to conjugate this root
     pub fn conjugation_suffix(&self) -> String {
         info!("conjugation_suffix {:?}: {:?}", self.kind, self.steps);
@@ -22,10 +36,18 @@ impl Root {
                 prev.root_kind()
             };
             debug!("kind: {kind:?}");
+            let prev_step = i.checked_sub(1).and_then(|i| self.steps.get(i));
+            // There is no next step, or it's disjoint from the current conjugation sequence
+            let next_step_disjoint = match self.steps.get(i + 1) {
+                Some(step) => matches!(step, Step::Ka | Step::Tteba),
+                None => true,
+            };
             let Some(kind) = kind else {
                 debug!("No root kind, special case spaghetti code:");
                 match step {
                     Step::Ka => text.push('か'),
+                    Step::Tteba => text.push_str("ってば"),
+                    Step::Naranai | Step::Ikenai | Step::Dame => push_must(step, &mut text),
                     Step::Nda => text.push_str("んだ"),
                     // Nai after something that doesn't have a root... I guess. Just push ない.
                     Step::Nai => text.push_str("ない"),
@@ -35,15 +57,27 @@ impl Root {
                     Step::Potential => text.push_str("られる"),
                     // もしかして…
                     Step::Ta => text.push('た'),
+                    Step::SouDa => text.push_str("そうだ"),
+                    Step::Darou
+                    | Step::Deshou
+                    | Step::Kamoshirenai
+                    | Step::Mitai
+                    | Step::You
+                    | Step::Rashii => push_presumptive(step, next_step_disjoint, &mut text),
+                    Step::Ato | Step::Mama | Step::Bakari | Step::Tokoro => {
+                        push_connective(step, &mut text)
+                    }
+                    // ～ませんです, ～かったです, etc.
+                    Step::Desu => {
+                        text.push('で');
+                        if next_step_disjoint {
+                            text.push('す');
+                        }
+                    }
                     _ => text.push_str("###BUG###"),
                 }
                 continue;
             };
-            // There is no next step, or it's disjoint from the current conjugation sequence
-            let next_step_disjoint = match self.steps.get(i + 1) {
-                Some(step) => matches!(step, Step::Ka),
-                None => true,
-            };
             match step {
                 Step::Te => {
                     push_te_root(kind, &mut text);
@@ -69,16 +103,32 @@ impl Root {
                     }
                 }
                 Step::Ta => push_ta(kind, &mut text),
-                Step::Nai => {
+                Step::Nai | Step::Kunai | Step::Janai => {
                     push_neg_root(kind, &mut text);
                     text.push('な');
                     if next_step_disjoint {
                         text.push('い');
                     }
                 }
-                Step::Nu => {
+                Step::Nu | Step::N | Step::Zuni => {
+                    push_literary_neg_root(kind, &mut text);
+                    text.push_str(match step {
+                        Step::Nu => "ぬ",
+                        Step::N => "ん",
+                        _ => "ずに",
+                    });
+                }
+                Step::Mai => match kind {
+                    RootKind::Ichidan | RootKind::Kuru => text.push_str("まい"),
+                    RootKind::Suru | RootKind::SpecialSuru => text.push_str("すまい"),
+                    _ => {
+                        push_terminal(kind, &mut text);
+                        text.push_str("まい");
+                    }
+                },
+                Step::Nee => {
                     push_neg_root(kind, &mut text);
-                    text.push('ぬ');
+                    text.push_str("ねえ");
                 }
                 Step::Naide => {
                     push_neg_root(kind, &mut text);
@@ -90,43 +140,51 @@ impl Root {
                     RootKind::GodanBu => text.push_str("ぼう"),
                     RootKind::GodanMu => text.push_str("もう"),
                     RootKind::GodanNu => text.push_str("のう"),
-                    RootKind::GodanRu => text.push_str("ろう"),
-                    RootKind::GodanSu => text.push_str("そう"),
+                    RootKind::GodanRu | RootKind::GodanAru => text.push_str("ろう"),
+                    RootKind::Aru => text.push_str("あろう"),
+                    RootKind::Zuru => text.push_str("じよう"),
+                    RootKind::GodanSu | RootKind::SpecialSuruGodan => text.push_str("そう"),
                     RootKind::GodanTsu => text.push_str("とう"),
-                    RootKind::GodanU => text.push_str("おう"),
+                    RootKind::GodanU | RootKind::GodanUOnbin => text.push_str("おう"),
                     RootKind::GodanGu => text.push_str("ごう"),
                     RootKind::GodanKu | RootKind::Iku => text.push_str("こう"),
-                    RootKind::IAdjective => todo!(),
-                    RootKind::NaAdjective => todo!(),
+                    RootKind::IAdjective | RootKind::Yoi => text.push_str("かろう"),
+                    RootKind::NaAdjective => text.push_str("だろう"),
                     RootKind::Suru | RootKind::SpecialSuru => text.push_str("しよう"),
                 },
                 Step::AdverbialKu => text.push('く'),
+                // くれる has an irregular imperative (くれ)
+                Step::Imperative if prev_step == Some(&Step::TeKureru) => {}
                 Step::Imperative => match kind {
                     RootKind::Ichidan => text.push('ろ'),
                     RootKind::GodanBu => text.push('べ'),
                     RootKind::GodanMu => text.push('め'),
                     RootKind::GodanNu => text.push('ね'),
                     RootKind::GodanRu => text.push('れ'),
-                    RootKind::GodanSu => text.push('せ'),
+                    RootKind::GodanAru => text.push('い'),
+                    RootKind::GodanSu | RootKind::SpecialSuruGodan => text.push('せ'),
                     RootKind::GodanTsu => text.push('て'),
-                    RootKind::GodanU => text.push('え'),
+                    RootKind::GodanU | RootKind::GodanUOnbin => text.push('え'),
                     RootKind::GodanGu => text.push('げ'),
                     RootKind::GodanKu | RootKind::Iku => text.push('け'),
-                    RootKind::IAdjective => todo!(),
+                    // Literary (よかれ, 多かれ少なかれ)
+                    RootKind::IAdjective | RootKind::Yoi => text.push_str("かれ"),
                     RootKind::Kuru => text.push('い'),
-                    RootKind::NaAdjective => todo!(),
+                    RootKind::Aru => text.push_str("あれ"),
+                    RootKind::Zuru => text.push_str("ぜよ"),
+                    RootKind::NaAdjective => text.push_str("であれ"),
                     RootKind::Suru | RootKind::SpecialSuru => text.push_str("しろ"),
                 },
-                Step::Masu | Step::Masen => {
+                Step::Masu => {
                     push_masu_root(kind, &mut text);
                     if next_step_disjoint {
-                        match step {
-                            Step::Masu => text.push('す'),
-                            Step::Masen => text.push_str("せん"),
-                            _ => {}
-                        }
+                        text.push('す');
                     }
                 }
+                Step::Masen => {
+                    push_masu_root(kind, &mut text);
+                    text.push_str("せん");
+                }
                 Step::Continuous => {
                     text.push('い');
                     if next_step_disjoint {
@@ -143,10 +201,18 @@ impl Root {
                     text.push_str("しょう");
                 }
                 Step::Zu => {
-                    push_neg_root(kind, &mut text);
+                    push_literary_neg_root(kind, &mut text);
                     text.push('ず');
                 }
                 Step::Ka => text.push('か'),
+                Step::Tteba => {
+                    // Auxiliary steps push their own plain form, but a bare root needs one
+                    if i == 0 {
+                        push_terminal(kind, &mut text);
+                    }
+                    text.push_str("ってば");
+                }
+                Step::Naranai | Step::Ikenai | Step::Dame => push_must(step, &mut text),
                 Step::Tari => {
                     push_ta(kind, &mut text);
                     text.push('り');
@@ -166,7 +232,7 @@ impl Root {
                 Step::Causative => {
                     push_neg_root(kind, &mut text);
                     match kind {
-                        RootKind::Ichidan => text.push_str("させ"),
+                        RootKind::Ichidan | RootKind::Zuru => text.push_str("させ"),
                         RootKind::Kuru => text.push_str("させ"),
                         RootKind::Suru | RootKind::SpecialSuru => {
                             text.pop();
@@ -179,23 +245,56 @@ impl Root {
                         text.push('る');
                     }
                 }
-                Step::Passive => {
-                    if matches!(self.kind, RootKind::Kuru) {
-                        text.push('ら');
-                    } else {
-                        push_neg_root(kind, &mut text);
+                Step::ShortCausative => {
+                    push_neg_root(kind, &mut text);
+                    match kind {
+                        RootKind::Ichidan | RootKind::Zuru | RootKind::Kuru => text.push('さ'),
+                        RootKind::Suru | RootKind::SpecialSuru => {
+                            text.pop();
+                            text.push('さ');
+                        }
+                        RootKind::IAdjective => text.push_str("###TODO###"),
+                        _ => {}
+                    }
+                    if next_step_disjoint {
+                        text.push('す');
                     }
-                    match self.kind {
-                        RootKind::Ichidan => text.push_str("られ"),
-                        RootKind::Kuru => text.push('れ'),
-                        RootKind::IAdjective => todo!(),
+                }
+                Step::Passive => {
+                    // The kind the causative was attached to, if this is a causative passive
+                    let causative_kind = match prev_step {
+                        Some(Step::Causative) => match i.checked_sub(2) {
+                            Some(i) => self.steps[i].root_kind(),
+                            None => Some(self.kind),
+                        },
+                        _ => None,
+                    };
+                    match kind {
+                        // Godan causative passive is shortened (まわらされる, not まわらせられる)
+                        RootKind::Ichidan
+                            if causative_kind.is_some_and(|kind| {
+                                !matches!(
+                                    kind,
+                                    RootKind::Ichidan
+                                        | RootKind::Kuru
+                                        | RootKind::Zuru
+                                        | RootKind::Suru
+                                        | RootKind::SpecialSuru
+                                )
+                            }) =>
+                        {
+                            text.pop();
+                            text.push_str("され");
+                        }
+                        RootKind::Ichidan | RootKind::Kuru => text.push_str("られ"),
+                        RootKind::Zuru => text.push_str("ぜられ"),
+                        // される, not しれる
+                        RootKind::Suru | RootKind::SpecialSuru => text.push_str("され"),
+                        RootKind::IAdjective | RootKind::Yoi | RootKind::NaAdjective => {
+                            text.push_str("###TODO###")
+                        }
                         _ => {
-                            if let Some(Step::Causative) =
-                                i.checked_sub(1).and_then(|i| self.steps.get(i))
-                            {
-                                text.pop();
-                                text.push('さ');
-                            }
+                            push_neg_root(kind, &mut text);
                             text.push('れ');
                         }
                     }
@@ -224,6 +323,22 @@ impl Root {
                         text.push('る');
                     }
                 }
+                Step::RanukiPotential => {
+                    match kind {
+                        RootKind::Ichidan | RootKind::Kuru => text.push('れ'),
+                        // する's potential is できる, and adjectives have none
+                        RootKind::Suru
+                        | RootKind::SpecialSuru
+                        | RootKind::IAdjective
+                        | RootKind::Yoi
+                        | RootKind::NaAdjective => text.push_str("###TODO###"),
+                        // Only ichidan and 来る have a ら to drop
+                        _ => push_e_root(kind, &mut text, false),
+                    }
+                    if next_step_disjoint {
+                        text.push('る');
+                    }
+                }
                 Step::Na => text.push('な'),
                 Step::Chau => {
                     push_te_root(kind, &mut text);
@@ -232,6 +347,30 @@ impl Root {
                         text.push('う');
                     }
                 }
+                Step::Chimau => {
+                    push_te_root(kind, &mut text);
+                    text.push(if te_char(kind) == 'で' { 'じ' } else { 'ち' });
+                    text.push('ま');
+                    if next_step_disjoint {
+                        text.push('う');
+                    }
+                }
+                Step::Cha => {
+                    push_te_root(kind, &mut text);
+                    push_chau_root(kind, &mut text);
+                }
+                Step::Nnai => {
+                    match kind {
+                        RootKind::GodanRu | RootKind::GodanAru => text.push('ん'),
+                        // Only godan る verbs contract
+                        _ => push_neg_root(kind, &mut text),
+                    }
+                    text.push('な');
+                    if next_step_disjoint {
+                        text.push('い');
+                    }
+                }
+                Step::BaAbbrev => push_ba_abbrev(kind, &mut text),
                 Step::Katta => {
                     text.push_str("かった");
                 }
@@ -241,13 +380,285 @@ impl Root {
                 Step::Ki => text.push('き'),
                 Step::Nda => text.push_str("んだ"),
                 Step::Kereba => text.push_str("ければ"),
-                Step::Nakya => {
-                    push_neg_root(kind, &mut text);
-                    text.push_str("なきゃ");
+                Step::Nakya | Step::Nakereba | Step::NakuteWa | Step::Nakucha | Step::Neba => {
+                    if step == &Step::Neba {
+                        push_literary_neg_root(kind, &mut text);
+                    } else {
+                        push_neg_root(kind, &mut text);
+                    }
+                    text.push_str(match step {
+                        Step::Nakya => "なきゃ",
+                        Step::Nakereba => "なければ",
+                        Step::NakuteWa => "なくては",
+                        Step::Nakucha => "なくちゃ",
+                        _ => "ねば",
+                    });
+                }
+                Step::Temo => {
+                    push_te_root(kind, &mut text);
+                    text.push(te_char(kind));
+                    text.push('も');
+                }
+                Step::Hen => match kind {
+                    RootKind::Ichidan | RootKind::Kuru => text.push_str("へん"),
+                    RootKind::Suru | RootKind::SpecialSuru => text.push_str("せえへん"),
+                    RootKind::Aru => text.push_str("あらへん"),
+                    RootKind::IAdjective | RootKind::Yoi => text.push_str("くあらへん"),
+                    RootKind::NaAdjective => text.push_str("やあらへん"),
+                    _ => {
+                        push_neg_root(kind, &mut text);
+                        text.push_str("へん");
+                    }
+                },
+                Step::Haru => {
+                    match kind {
+                        RootKind::Ichidan
+                        | RootKind::Kuru
+                        | RootKind::Suru
+                        | RootKind::SpecialSuru => push_masu_root_naked(kind, &mut text),
+                        _ => push_neg_root(kind, &mut text),
+                    }
+                    text.push('は');
+                    if next_step_disjoint {
+                        text.push('る');
+                    }
+                }
+                Step::Toru => {
+                    push_te_root(kind, &mut text);
+                    text.push(if te_char(kind) == 'で' { 'ど' } else { 'と' });
+                    if next_step_disjoint {
+                        text.push('る');
+                    }
+                }
+                // The following te/ta step picks up the う音便 from the root kind
+                Step::UOnbin => {}
+                Step::Ten => {
+                    push_te_root(kind, &mut text);
+                    text.push(te_char(kind));
+                    text.push('ん');
+                }
+                Step::Ya => {
+                    if kind != RootKind::NaAdjective {
+                        push_terminal(kind, &mut text);
+                        text.push('ん');
+                    }
+                    text.push('や');
+                }
+                Step::Tatte => match kind {
+                    RootKind::IAdjective | RootKind::Yoi => text.push_str("くたって"),
+                    RootKind::NaAdjective => text.push_str("だって"),
+                    _ => {
+                        push_ta(kind, &mut text);
+                        text.push_str("って");
+                    }
+                },
+                Step::TeWaIkenai => {
+                    push_te_root(kind, &mut text);
+                    text.push(te_char(kind));
+                    text.push_str("はいけない");
+                }
+                Step::ChaIkenai | Step::ChaDame => {
+                    push_te_root(kind, &mut text);
+                    push_chau_root(kind, &mut text);
+                    text.push_str(if step == &Step::ChaIkenai {
+                        "いけない"
+                    } else {
+                        "だめ"
+                    });
                 }
                 Step::Sa => {
                     text.push('さ');
                 }
+                Step::Da => text.push('だ'),
+                Step::Dewanai => {
+                    text.push_str("ではな");
+                    if next_step_disjoint {
+                        text.push('い');
+                    }
+                }
+                Step::Nara => text.push_str("なら"),
+                Step::Ni => text.push('に'),
+                Step::Desu => {
+                    if matches!(kind, RootKind::IAdjective | RootKind::Yoi) {
+                        text.push('い');
+                    }
+                    text.push('で');
+                    if next_step_disjoint {
+                        text.push('す');
+                    }
+                }
+                Step::Sou => {
+                    push_aux_stem(kind, prev_step, &mut text);
+                    text.push_str("そう");
+                }
+                Step::Sugiru => {
+                    push_aux_stem(kind, prev_step, &mut text);
+                    text.push_str("すぎ");
+                    if next_step_disjoint {
+                        text.push('る');
+                    }
+                }
+                Step::Tagaru => {
+                    push_masu_root_naked(kind, &mut text);
+                    text.push_str("たが");
+                    if next_step_disjoint {
+                        text.push('る');
+                    }
+                }
+                Step::Garu => {
+                    text.push('が');
+                    if next_step_disjoint {
+                        text.push('る');
+                    }
+                }
+                Step::TeHoshii => {
+                    push_te_root(kind, &mut text);
+                    text.push(te_char(kind));
+                    text.push_str("ほし");
+                    if next_step_disjoint {
+                        text.push('い');
+                    }
+                }
+                // The ある part conjugates as an Aru root, so only the plain form needs it here
+                Step::TeAru => {
+                    push_te_root(kind, &mut text);
+                    text.push(te_char(kind));
+                    if next_step_disjoint {
+                        text.push_str("ある");
+                    }
+                }
+                Step::TeMiru
+                | Step::TeIku
+                | Step::TeShimau
+                | Step::TeKureru
+                | Step::TeMorau
+                | Step::TeAgeru
+                | Step::TeYaru
+                | Step::TeKudasaru
+                | Step::TeItadaku => {
+                    push_te_root(kind, &mut text);
+                    text.push(te_char(kind));
+                    let (stem, ending) = match step {
+                        Step::TeMiru => ("み", 'る'),
+                        Step::TeIku => ("い", 'く'),
+                        Step::TeShimau => ("しま", 'う'),
+                        Step::TeKureru => ("くれ", 'る'),
+                        Step::TeMorau => ("もら", 'う'),
+                        Step::TeAgeru => ("あげ", 'る'),
+                        Step::TeYaru => ("や", 'る'),
+                        Step::TeKudasaru => ("くださ", 'る'),
+                        _ => ("いただ", 'く'),
+                    };
+                    text.push_str(stem);
+                    if next_step_disjoint {
+                        text.push(ending);
+                    }
+                }
+                Step::TeKuru => {
+                    push_te_root(kind, &mut text);
+                    text.push(te_char(kind));
+                    // Unlike 来る roots, this is usually written in kana, so we need the stem
+                    match self.steps.get(i + 1) {
+                        None | Some(Step::Ka | Step::Tteba | Step::Nda) => text.push_str("くる"),
+                        Some(
+                            Step::Nai
+                            | Step::Nakatta
+                            | Step::Naide
+                            | Step::Nu
+                            | Step::N
+                            | Step::Zu
+                            | Step::Zuni
+                            | Step::Mai
+                            | Step::Nee
+                            | Step::Nakya
+                            | Step::Nakereba
+                            | Step::NakuteWa
+                            | Step::Nakucha
+                            | Step::Neba
+                            | Step::Hen
+                            | Step::Volitional
+                            | Step::Imperative
+                            | Step::Passive
+                            | Step::Potential
+                            | Step::RanukiPotential
+                            | Step::Causative
+                            | Step::ShortCausative,
+                        ) => text.push('こ'),
+                        // Steps that take the plain form push the る themselves
+                        Some(
+                            Step::Ba
+                            | Step::BaAbbrev
+                            | Step::SouDa
+                            | Step::Darou
+                            | Step::Deshou
+                            | Step::Kamoshirenai
+                            | Step::Rashii
+                            | Step::Mitai
+                            | Step::You
+                            | Step::Ya,
+                        ) => text.push('く'),
+                        Some(_) => text.push('き'),
+                    }
+                }
+                Step::SouDa => {
+                    push_terminal(kind, &mut text);
+                    text.push_str("そうだ");
+                }
+                Step::ONiNaru => {
+                    push_honorific_stem(kind, &mut text);
+                    text.push_str("にな");
+                    if next_step_disjoint {
+                        text.push('る');
+                    }
+                }
+                Step::OSuru => {
+                    push_honorific_stem(kind, &mut text);
+                    if next_step_disjoint {
+                        text.push_str("する");
+                    }
+                }
+                Step::OKudasai => {
+                    push_honorific_stem(kind, &mut text);
+                    text.push_str("ください");
+                }
+                Step::Itasu => {
+                    // れんらくいたす, おまちいたす
+                    push_honorific_stem(kind, &mut text);
+                    text.push_str("いた");
+                    if next_step_disjoint {
+                        text.push('す');
+                    }
+                }
+                Step::Gozaimasu => {
+                    if kind == RootKind::NaAdjective {
+                        text.push('で');
+                    }
+                    text.push_str("ございま");
+                    if next_step_disjoint {
+                        text.push('す');
+                    }
+                }
+                Step::Darou
+                | Step::Deshou
+                | Step::Kamoshirenai
+                | Step::Mitai
+                | Step::You
+                | Step::Rashii => {
+                    match kind {
+                        // しずかなようだ
+                        RootKind::NaAdjective if step == &Step::You => text.push('な'),
+                        // しずかだろう, しずからしい, etc.
+                        RootKind::NaAdjective => {}
+                        _ => push_terminal(kind, &mut text),
+                    }
+                    push_presumptive(step, next_step_disjoint, &mut text);
+                }
+                Step::Kara | Step::Ato | Step::Mama | Step::Bakari | Step::Tokoro => {
+                    push_connective(step, &mut text)
+                }
+                Step::KuArimasen => text.push_str("くありません"),
+                Step::JaArimasen => text.push_str("じゃありません"),
+                Step::DewaArimasen => text.push_str("ではありません"),
             }
         }
         text
@@ -257,32 +668,46 @@ impl Root {
 fn te_char(kind: RootKind) -> char {
     match kind {
         RootKind::IAdjective
+        | RootKind::Yoi
         | RootKind::Kuru
         | RootKind::Iku
         | RootKind::GodanKu
         | RootKind::GodanU
+        | RootKind::GodanUOnbin
         | RootKind::GodanTsu
         | RootKind::GodanSu
+        | RootKind::SpecialSuruGodan
         | RootKind::GodanRu
+        | RootKind::GodanAru
+        | RootKind::Aru
         | RootKind::Suru
         | RootKind::SpecialSuru
+        | RootKind::Zuru
         | RootKind::Ichidan => 'て',
-        RootKind::GodanGu | RootKind::GodanNu | RootKind::GodanMu | RootKind::GodanBu => 'で',
-        RootKind::NaAdjective => todo!(),
+        RootKind::GodanGu
+        | RootKind::GodanNu
+        | RootKind::GodanMu
+        | RootKind::GodanBu
+        | RootKind::NaAdjective => 'で',
     }
 }
 
 fn push_te_root(kind: RootKind, text: &mut String) {
     debug!("push_te_root");
     match kind {
-        RootKind::Ichidan | RootKind::Kuru | RootKind::IAdjective => {}
+        RootKind::Ichidan | RootKind::Kuru | RootKind::NaAdjective => {}
+        RootKind::IAdjective | RootKind::Yoi => text.push('く'),
         RootKind::GodanBu | RootKind::GodanMu | RootKind::GodanNu => text.push('ん'),
-        RootKind::GodanRu | RootKind::GodanTsu | RootKind::GodanU | RootKind::Iku => {
-            text.push('っ')
-        }
-        RootKind::GodanSu => text.push('し'),
+        RootKind::GodanRu
+        | RootKind::GodanAru
+        | RootKind::GodanTsu
+        | RootKind::GodanU
+        | RootKind::Iku => text.push('っ'),
+        RootKind::Aru => text.push_str("あっ"),
+        RootKind::GodanUOnbin => text.push('う'),
+        RootKind::Zuru => text.push('じ'),
+        RootKind::GodanSu | RootKind::SpecialSuruGodan => text.push('し'),
         RootKind::GodanGu | RootKind::GodanKu => text.push('い'),
-        RootKind::NaAdjective => todo!(),
         RootKind::Suru | RootKind::SpecialSuru => {
             text.push('し');
         }
@@ -296,15 +721,22 @@ fn push_chau_root(kind: RootKind, text: &mut String) {
         }
         RootKind::GodanKu
         | RootKind::GodanU
+        | RootKind::GodanUOnbin
         | RootKind::GodanTsu
         | RootKind::GodanSu
+        | RootKind::SpecialSuruGodan
         | RootKind::GodanRu
+        | RootKind::GodanAru
         | RootKind::Ichidan
         | RootKind::Iku
+        | RootKind::Aru
         | RootKind::Suru
         | RootKind::SpecialSuru
-        | RootKind::Kuru => text.push_str("ちゃ"),
-        RootKind::IAdjective | RootKind::NaAdjective => {}
+        | RootKind::Zuru
+        | RootKind::Kuru
+        | RootKind::IAdjective
+        | RootKind::Yoi => text.push_str("ちゃ"),
+        RootKind::NaAdjective => text.push_str("じゃ"),
     }
 }
 
@@ -314,50 +746,102 @@ fn push_e_root(kind: RootKind, text: &mut String, ba: bool) {
         RootKind::GodanBu => text.push('べ'),
         RootKind::GodanMu => text.push('め'),
         RootKind::GodanNu => text.push('ね'),
-        RootKind::GodanRu => text.push('れ'),
-        RootKind::GodanSu => text.push('せ'),
+        RootKind::GodanRu | RootKind::GodanAru => text.push('れ'),
+        RootKind::GodanSu | RootKind::SpecialSuruGodan => text.push('せ'),
         RootKind::GodanTsu => text.push('て'),
-        RootKind::GodanU => text.push('え'),
+        RootKind::GodanU | RootKind::GodanUOnbin => text.push('え'),
         RootKind::GodanGu => text.push('げ'),
         RootKind::GodanKu | RootKind::Iku => text.push('け'),
-        RootKind::Kuru => text.push_str("られ"),
-        RootKind::IAdjective => todo!(),
-        RootKind::NaAdjective => todo!(),
+        RootKind::Kuru => text.push_str(if ba { "れ" } else { "られ" }),
+        RootKind::Aru => text.push_str("あれ"),
+        RootKind::Zuru => text.push_str(if ba { "ずれ" } else { "ぜられ" }),
+        // よければ, but adjectives have no potential
+        RootKind::IAdjective | RootKind::Yoi => {
+            text.push_str(if ba { "けれ" } else { "###TODO###" })
+        }
+        // しずかであれば, but there's no potential
+        RootKind::NaAdjective => text.push_str(if ba { "であれ" } else { "###TODO###" }),
         RootKind::Suru | RootKind::SpecialSuru => todo!("できる special case needed(?)"),
     }
 }
 
+/// Contracted conditional, where the e row kana and ば merge (いけば → いきゃ)
+fn push_ba_abbrev(kind: RootKind, text: &mut String) {
+    match kind {
+        RootKind::IAdjective | RootKind::Yoi => text.push_str("けりゃ"),
+        RootKind::NaAdjective => text.push_str("でありゃ"),
+        RootKind::Suru | RootKind::SpecialSuru => text.push_str("すりゃ"),
+        _ => {
+            push_e_root(kind, text, true);
+            let contracted = match text.pop() {
+                Some('え') => "や",
+                Some('け') => "きゃ",
+                Some('げ') => "ぎゃ",
+                Some('せ') => "しゃ",
+                Some('て') => "ちゃ",
+                Some('ね') => "にゃ",
+                Some('べ') => "びゃ",
+                Some('め') => "みゃ",
+                Some('れ') => "りゃ",
+                _ => "###BUG###",
+            };
+            text.push_str(contracted);
+        }
+    }
+}
+
 fn push_neg_root(kind: RootKind, text: &mut String) {
     match kind {
         RootKind::Ichidan => {}
         RootKind::GodanBu => text.push('ば'),
         RootKind::GodanMu => text.push('ま'),
         RootKind::GodanNu => text.push('な'),
-        RootKind::GodanRu => text.push('ら'),
-        RootKind::GodanSu => text.push('さ'),
+        RootKind::GodanRu | RootKind::GodanAru => text.push('ら'),
+        RootKind::GodanSu | RootKind::SpecialSuruGodan => text.push('さ'),
         RootKind::GodanTsu => text.push('た'),
-        RootKind::GodanU => text.push('わ'),
+        RootKind::GodanU | RootKind::GodanUOnbin => text.push('わ'),
         RootKind::GodanGu => text.push('が'),
         RootKind::GodanKu => text.push('か'),
         RootKind::Iku => text.push('か'),
         RootKind::Kuru => {}
-        RootKind::IAdjective => text.push_str("###TODO###"),
-        RootKind::NaAdjective => todo!(),
+        // ない, not あらない
+        RootKind::Aru => {}
+        RootKind::Zuru => text.push('ぜ'),
+        RootKind::IAdjective | RootKind::Yoi => text.push('く'),
+        RootKind::NaAdjective => text.push_str("じゃ"),
         RootKind::Suru | RootKind::SpecialSuru => text.push('し'),
     }
 }
 
+/// Negative root for ず, ぬ, ん, etc. (せず, not しず)
+fn push_literary_neg_root(kind: RootKind, text: &mut String) {
+    match kind {
+        RootKind::Suru | RootKind::SpecialSuru => text.push('せ'),
+        // あらず
+        RootKind::Aru => text.push_str("あら"),
+        _ => push_neg_root(kind, text),
+    }
+}
+
 fn push_ta(kind: RootKind, text: &mut String) {
     match kind {
         RootKind::Ichidan | RootKind::Kuru => text.push('た'),
         RootKind::GodanBu | RootKind::GodanMu | RootKind::GodanNu => text.push_str("んだ"),
-        RootKind::GodanRu | RootKind::GodanTsu | RootKind::Iku => text.push_str("った"),
-        RootKind::GodanSu | RootKind::Suru | RootKind::SpecialSuru => text.push_str("した"),
+        RootKind::GodanRu | RootKind::GodanAru | RootKind::GodanTsu | RootKind::Iku => {
+            text.push_str("った")
+        }
+        RootKind::GodanSu | RootKind::SpecialSuruGodan | RootKind::Suru | RootKind::SpecialSuru => {
+            text.push_str("した")
+        }
         RootKind::GodanU => text.push_str("った"),
+        // 問うた, not 問った
+        RootKind::GodanUOnbin => text.push_str("うた"),
+        RootKind::Aru => text.push_str("あった"),
+        RootKind::Zuru => text.push_str("じた"),
         RootKind::GodanGu => text.push_str("いだ"),
         RootKind::GodanKu => text.push_str("いた"),
-        RootKind::IAdjective => todo!(),
-        RootKind::NaAdjective => todo!(),
+        RootKind::IAdjective | RootKind::Yoi => text.push_str("かった"),
+        RootKind::NaAdjective => text.push_str("だった"),
     }
 }
 
@@ -366,6 +850,14 @@ fn push_masu_root(kind: RootKind, text: &mut String) {
     text.push('ま');
 }
 
+/// Masu stem, but without the し of suru nouns (おまちください, ごれんらくください)
+fn push_honorific_stem(kind: RootKind, text: &mut String) {
+    match kind {
+        RootKind::Suru | RootKind::SpecialSuru => {}
+        _ => push_masu_root_naked(kind, text),
+    }
+}
+
 fn push_masu_root_naked(kind: RootKind, text: &mut String) {
     match kind {
         RootKind::Ichidan => {}
@@ -373,13 +865,98 @@ fn push_masu_root_naked(kind: RootKind, text: &mut String) {
         RootKind::GodanMu => text.push('み'),
         RootKind::GodanNu => text.push('に'),
         RootKind::GodanRu => text.push('り'),
-        RootKind::GodanSu => text.push('し'),
+        RootKind::GodanAru => text.push('い'),
+        RootKind::GodanSu | RootKind::SpecialSuruGodan => text.push('し'),
         RootKind::GodanTsu => text.push('ち'),
-        RootKind::GodanU => text.push('い'),
+        RootKind::GodanU | RootKind::GodanUOnbin => text.push('い'),
         RootKind::GodanGu => text.push('ぎ'),
         RootKind::GodanKu => text.push('き'),
         RootKind::Iku => text.push('き'),
         RootKind::Kuru => {}
+        RootKind::Aru => text.push_str("あり"),
+        RootKind::Zuru => text.push('じ'),
+        RootKind::Suru | RootKind::SpecialSuru => text.push('し'),
         _ => {}
     }
 }
+
+/// The stem auxiliaries like そう and すぎる attach to (たべ, たか, よさ, なさ)
+fn push_aux_stem(kind: RootKind, prev_step: Option<&Step>, text: &mut String) {
+    match kind {
+        RootKind::IAdjective => {
+            // ない has an irregular stem (なさ)
+            if matches!(
+                prev_step,
+                Some(Step::Nai | Step::Kunai | Step::Janai | Step::Dewanai)
+            ) {
+                text.push('さ');
+            }
+        }
+        RootKind::Yoi => text.push('さ'),
+        _ => push_masu_root_naked(kind, text),
+    }
+}
+
+fn push_presumptive(step: &Step, next_step_disjoint: bool, text: &mut String) {
+    match step {
+        Step::Darou => text.push_str("だろう"),
+        Step::Deshou => text.push_str("でしょう"),
+        Step::Mitai => text.push_str("みたい"),
+        Step::You => text.push_str("よう"),
+        Step::Kamoshirenai | Step::Rashii => {
+            text.push_str(if step == &Step::Kamoshirenai {
+                "かもしれな"
+            } else {
+                "らし"
+            });
+            if next_step_disjoint {
+                text.push('い');
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Second half of an obligation (なければならない, なきゃいけない, etc.)
+fn push_must(step: &Step, text: &mut String) {
+    text.push_str(match step {
+        Step::Naranai => "ならない",
+        Step::Ikenai => "いけない",
+        Step::Dame => "だめ",
+        _ => return,
+    });
+}
+
+/// Connectives following the て or た form (てから, たあと, etc.)
+fn push_connective(step: &Step, text: &mut String) {
+    text.push_str(match step {
+        Step::Kara => "から",
+        Step::Ato => "あと",
+        Step::Mama => "まま",
+        Step::Bakari => "ばかり",
+        Step::Tokoro => "ところ",
+        _ => return,
+    });
+}
+
+/// Plain (dictionary) form ending
+fn push_terminal(kind: RootKind, text: &mut String) {
+    match kind {
+        RootKind::Ichidan | RootKind::GodanRu | RootKind::GodanAru => text.push('る'),
+        RootKind::GodanBu => text.push('ぶ'),
+        RootKind::GodanMu => text.push('む'),
+        RootKind::GodanNu => text.push('ぬ'),
+        RootKind::GodanSu | RootKind::SpecialSuruGodan => text.push('す'),
+        RootKind::GodanTsu => text.push('つ'),
+        RootKind::GodanU | RootKind::GodanUOnbin => text.push('う'),
+        RootKind::GodanGu => text.push('ぐ'),
+        RootKind::GodanKu | RootKind::Iku => text.push('く'),
+        // Assumes 来 kanji, like the rest of the 来る handling
+        RootKind::Kuru => text.push('る'),
+        RootKind::Aru => text.push_str("ある"),
+        RootKind::Zuru => text.push_str("ずる"),
+        RootKind::Suru | RootKind::SpecialSuru => text.push_str("する"),
+        RootKind::IAdjective | RootKind::Yoi => text.push('い'),
+        RootKind::NaAdjective => text.push('だ'),
+    }
+}
@@ -10,10 +10,184 @@ pub fn deconjugate(word: &str) -> Vec<Root> {
     let steps = vec![];
     debug!("<BEGIN> deconjugate({word})");
     deconj_expr(&chars, &mut roots, steps);
+    push_aru_roots(&mut roots);
     debug!("<END> deconjugate({word}) = {roots:#?}");
     roots
 }
 
+/// Like [`deconjugate`], but also considers Kansai dialect forms (わからへん, いかはる, しとる, etc.)
+pub fn deconjugate_kansai(word: &str) -> Vec<Root> {
+    let mut roots = Vec::new();
+    let chars: Vec<char> = word.chars().collect();
+    let steps = vec![];
+    debug!("<BEGIN> deconjugate_kansai({word})");
+    deconj_expr(&chars, &mut roots, steps.clone());
+    deconj_kansai(&chars, &mut roots, steps);
+    push_kansai_roots(&chars, &mut roots);
+    push_aru_roots(&mut roots);
+    debug!("<END> deconjugate_kansai({word}) = {roots:#?}");
+    roots
+}
+
+fn deconj_kansai(chars: &[char], roots: &mut Vec<Root>, steps: Vec<Step>) {
+    info!("deconj_kansai: {chars:?}, {steps:?}");
+    if let Some((chars, ['へ' | 'ひ', 'ん'])) = chars.split_last_chunk() {
+        deconj_hen(roots, chars, steps.clone().with(Step::Hen));
+    }
+    if let Some((chars, ['て', 'ん'])) = chars.split_last_chunk() {
+        push_te_root(roots, chars, steps.clone().with(Step::Ten));
+        push_kansai_u_onbin_root(roots, chars, steps.clone().with(Step::Ten));
+    }
+    if let Some((chars, ['で', 'ん'])) = chars.split_last_chunk() {
+        push_de_root(roots, chars, steps.clone().with(Step::Ten));
+    }
+    if let Some((chars, ['て'])) = chars.split_last_chunk() {
+        push_kansai_u_onbin_root(roots, chars, steps.clone().with(Step::Te));
+    }
+    if let Some((chars, ['た'])) = chars.split_last_chunk() {
+        push_kansai_u_onbin_root(roots, chars, steps.clone().with(Step::Ta));
+    }
+    if let Some(('や', chars)) = chars.split_last() {
+        push_na_adjective_root(roots, chars, steps.clone().with(Step::Ya));
+        if let Some(('ん', chars)) = chars.split_last() {
+            push_kansai_plain_root(roots, chars, steps.with(Step::Ya));
+        }
+    }
+}
+
+/// Plain form before んや. Only verbs and い adjectives, since conjugated forms can't be rebuilt from a root.
+fn push_kansai_plain_root(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
+    push_verb_terminal_root(chars, roots, steps.clone());
+    match chars.split_last() {
+        Some(('る', stem)) => roots.ichidan(stem.to_string(), steps),
+        Some(('い', stem)) => push_bare_i_adjective_root(roots, stem, steps),
+        _ => {}
+    }
+}
+
+/// Kansai negative. Mostly the same root as ない, but する and 来る have their own forms.
+fn deconj_hen(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
+    match chars.split_last_chunk() {
+        Some((stem, ['せ', 'え' | 'ー'] | ['し', 'い'])) => {
+            for kind in [RootKind::Suru, RootKind::SpecialSuru] {
+                roots.push(Root {
+                    text: stem.to_string(),
+                    kind,
+                    steps: steps.clone(),
+                });
+            }
+        }
+        Some((stem, ['け' | 'こ', 'え' | 'お' | 'ー'] | ['き', 'い'])) => roots.push(Root {
+            text: stem.to_string(),
+            kind: RootKind::Kuru,
+            steps: steps.clone(),
+        }),
+        _ => {}
+    }
+    // たかくあらへん, しずかやあらへん
+    match chars.split_last_chunk() {
+        Some((stem, ['く', 'あ', 'ら'])) => push_i_adjective_root(roots, stem, steps.clone()),
+        Some((stem, ['や', 'あ', 'ら'])) => push_na_adjective_root(roots, stem, steps.clone()),
+        _ => {}
+    }
+    push_negative_root(chars, roots, steps.clone());
+    // わかれへん, いけへん
+    push_e_root(roots, chars, steps, false);
+}
+
+/// Kansai う verbs take うて/うた everywhere (ゆうて, こうた)
+fn push_kansai_u_onbin_root(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
+    if let Some(('う', stem)) = chars.split_last() {
+        roots.push(Root {
+            text: stem.to_string(),
+            kind: RootKind::GodanU,
+            steps: steps.with(Step::UOnbin),
+        });
+    }
+}
+
+/// はる and とる conjugate like godan る verbs, so look for them in the godan る roots
+fn push_kansai_roots(chars: &[char], roots: &mut Vec<Root>) {
+    let mut godan_ru_roots: Vec<Root> = roots
+        .iter()
+        .filter(|root| root.kind == RootKind::GodanRu)
+        .cloned()
+        .collect();
+    // Plain いかはる, しとる
+    if let Some(('る', stem)) = chars.split_last() {
+        godan_ru_roots.push(Root {
+            text: stem.to_string(),
+            kind: RootKind::GodanRu,
+            steps: vec![],
+        });
+    }
+    let mut kansai_roots = Vec::new();
+    for root in godan_ru_roots {
+        let text: Vec<char> = root.text.chars().collect();
+        match text.split_last() {
+            Some(('は', stem)) => {
+                let steps = root.steps.with(Step::Haru);
+                push_other_negative_root(stem, &mut kansai_roots, steps.clone());
+                push_masu_root(stem, &mut kansai_roots, steps);
+            }
+            Some(('と', stem)) => {
+                push_te_root(&mut kansai_roots, stem, root.steps.with(Step::Toru))
+            }
+            Some(('ど', stem)) => {
+                push_de_root(&mut kansai_roots, stem, root.steps.with(Step::Toru))
+            }
+            _ => {}
+        }
+    }
+    roots.extend(kansai_roots);
+}
+
+/// ある conjugates like a godan る verb, except for the ない forms
+fn push_aru_roots(roots: &mut Vec<Root>) {
+    let aru_roots: Vec<Root> = roots
+        .iter()
+        .filter(|root| {
+            root.kind == RootKind::GodanRu
+                && !matches!(
+                    root.steps.first(),
+                    Some(
+                        Step::Nai
+                            | Step::Nakatta
+                            | Step::Naide
+                            | Step::Nee
+                            | Step::Nakya
+                            | Step::Nakereba
+                            | Step::NakuteWa
+                            | Step::Nakucha
+                            | Step::Nnai
+                            | Step::Causative
+                            | Step::Passive
+                    )
+                )
+        })
+        .filter_map(|root| {
+            Some(Root {
+                text: root.text.strip_suffix('あ')?.to_owned(),
+                kind: RootKind::Aru,
+                steps: root.steps.clone(),
+            })
+        })
+        .collect();
+    roots.extend(aru_roots);
+    // てある, which conjugates like ある itself
+    let mut te_aru_roots = Vec::new();
+    for root in roots.iter().filter(|root| root.kind == RootKind::Aru) {
+        let chars: Vec<char> = root.text.chars().collect();
+        deconj_te_aux_root(
+            &mut te_aru_roots,
+            &chars,
+            &[],
+            root.steps.clone().with(Step::TeAru),
+        );
+    }
+    roots.extend(te_aru_roots);
+}
+
 fn deconj_expr(chars: &[char], roots: &mut Vec<Root>, steps: Vec<Step>) {
     info!("deconj_expr: {chars:?}, {steps:?}");
     // Anything can be an い adjective root (I guess)
@@ -28,9 +202,19 @@ fn deconj_expr(chars: &[char], roots: &mut Vec<Root>, steps: Vec<Step>) {
         kind: RootKind::Ichidan,
         steps: steps.clone().with(Step::Stem),
     });
+    deconj_conjugated(chars, roots, steps);
+}
+
+/// Conjugated forms, without the catch-all roots of [`deconj_expr`].
+/// Used for text in plain form before an auxiliary (だろう, らしい, etc.)
+fn deconj_conjugated(chars: &[char], roots: &mut Vec<Root>, steps: Vec<Step>) {
+    debug!("deconj_conjugated: {chars:?}, {steps:?}");
     if let Some((chars, ['け', 'れ', 'ば'])) = chars.split_last_chunk() {
         push_i_adjective_root(roots, chars, steps.clone().with(Step::Kereba));
     }
+    if deconj_obligation(roots, chars, steps.clone()) {
+        return;
+    }
     if let Some((chars, ['な', 'き', 'ゃ'])) = chars.split_last_chunk() {
         push_negative_root(chars, roots, steps.with(Step::Nakya));
         return;
@@ -39,6 +223,107 @@ fn deconj_expr(chars: &[char], roots: &mut Vec<Root>, steps: Vec<Step>) {
         push_masu_root(chars, roots, steps.clone().with(Step::Masu));
         return;
     }
+    if let Some((_, ['そ', 'う'] | ['よ', 'う'])) = chars.split_last_chunk() {
+        push_na_adjective_root(roots, chars, steps.clone());
+    }
+    if let Some((_, ['み', 'た', 'い'])) = chars.split_last_chunk() {
+        push_na_adjective_root(roots, chars, steps.clone());
+    }
+    if let Some((chars, ['だ', 'ろ', 'う'])) = chars.split_last_chunk() {
+        deconj_presumptive(roots, chars, steps.clone().with(Step::Darou));
+    }
+    if let Some((chars, ['で', 'し', 'ょ', 'う'])) = chars.split_last_chunk() {
+        deconj_presumptive(roots, chars, steps.clone().with(Step::Deshou));
+    }
+    if let Some((chars, ['そ', 'う', 'だ'])) = chars.split_last_chunk() {
+        deconj_terminal(chars, roots, steps.clone().with(Step::SouDa));
+    }
+    if let Some((chars, ['で', 'す'])) = chars.split_last_chunk() {
+        deconj_desu(roots, chars, steps.clone().with(Step::Desu));
+    }
+    if let Some((chars, ['く', 'あ', 'り', 'ま', 'せ', 'ん'])) = chars.split_last_chunk() {
+        push_i_adjective_root(roots, chars, steps.clone().with(Step::KuArimasen));
+    }
+    if let Some((chars, ['じ', 'ゃ', 'あ', 'り', 'ま', 'せ', 'ん'])) = chars.split_last_chunk()
+    {
+        push_na_adjective_root(roots, chars, steps.clone().with(Step::JaArimasen));
+    }
+    if let Some((chars, ['で', 'は', 'あ', 'り', 'ま', 'せ', 'ん'])) = chars.split_last_chunk()
+    {
+        push_na_adjective_root(roots, chars, steps.clone().with(Step::DewaArimasen));
+    }
+    if let Some((chars, ['ず', 'に'])) = chars.split_last_chunk() {
+        push_literary_negative_root(chars, roots, steps.clone().with(Step::Zuni));
+    }
+    if let Some((chars, ['ま', 'い'])) = chars.split_last_chunk() {
+        deconj_mai(roots, chars, steps.clone().with(Step::Mai));
+    }
+    if let Some((chars, ['ね', 'え' | 'ー'])) = chars.split_last_chunk() {
+        deconj_nee(roots, chars, steps.clone().with(Step::Nee));
+    }
+    if let Some((chars, ['て', 'も'])) = chars.split_last_chunk() {
+        if let Some(('く', chars)) = chars.split_last() {
+            push_i_adjective_root(roots, chars, steps.clone().with(Step::Temo));
+        }
+        push_te_root(roots, chars, steps.clone().with(Step::Temo));
+    }
+    if let Some((chars, ['で', 'も'])) = chars.split_last_chunk() {
+        push_na_adjective_root(roots, chars, steps.clone().with(Step::Temo));
+        push_de_root(roots, chars, steps.clone().with(Step::Temo));
+    }
+    if let Some((chars, ['た', 'っ', 'て'])) = chars.split_last_chunk() {
+        if let Some(('く', chars)) = chars.split_last() {
+            push_i_adjective_root(roots, chars, steps.clone().with(Step::Tatte));
+        }
+        push_ta_root(chars, roots, steps.clone().with(Step::Tatte));
+    }
+    if let Some((chars, ['だ', 'っ', 'て'])) = chars.split_last_chunk() {
+        push_na_adjective_root(roots, chars, steps.clone().with(Step::Tatte));
+        push_da_root(chars, roots, steps.clone().with(Step::Tatte));
+    }
+    if let Some((chars, ['か', 'ら'])) = chars.split_last_chunk() {
+        deconj_te_connective(roots, chars, steps.clone().with(Step::Kara));
+    }
+    if let Some((chars, ['あ', 'と'])) = chars.split_last_chunk() {
+        deconj_ta_connective(roots, chars, steps.clone().with(Step::Ato));
+    }
+    if let Some((chars, ['ま', 'ま'])) = chars.split_last_chunk() {
+        deconj_ta_connective(roots, chars, steps.clone().with(Step::Mama));
+    }
+    if let Some((chars, ['ば', 'か', 'り'])) = chars.split_last_chunk() {
+        deconj_ta_connective(roots, chars, steps.clone().with(Step::Bakari));
+    }
+    if let Some((chars, ['と', 'こ', 'ろ'])) = chars.split_last_chunk() {
+        deconj_ta_connective(roots, chars, steps.clone().with(Step::Tokoro));
+    }
+    if let Some((chars, ['っ', 'て', 'ば'])) = chars.split_last_chunk() {
+        deconj_terminal(chars, roots, steps.clone().with(Step::Tteba));
+    }
+    if let Some((chars, ['ん', 'な', 'い'])) = chars.split_last_chunk() {
+        push_godan_ru_root(roots, chars, steps.clone().with(Step::Nnai));
+    }
+    if let Some((chars, ['ん', 'な', 'か', 'っ', 'た'])) = chars.split_last_chunk() {
+        push_godan_ru_root(
+            roots,
+            chars,
+            steps.clone().with(Step::Katta).with(Step::Nnai),
+        );
+    }
+    if let Some((chars, ['ち', 'ゃ'])) = chars.split_last_chunk() {
+        if let Some(('く', chars)) = chars.split_last() {
+            push_i_adjective_root(roots, chars, steps.clone().with(Step::Cha));
+        }
+        push_te_root(roots, chars, steps.clone().with(Step::Cha));
+    }
+    if let Some((chars, ['じ', 'ゃ'])) = chars.split_last_chunk() {
+        push_de_root(roots, chars, steps.clone().with(Step::Cha));
+    }
+    // Contracted conditional (すりゃ, いきゃ, なけりゃ)
+    if let Some(('ゃ', chars)) = chars.split_last()
+        && let Some('き' | 'り') = chars.last()
+    {
+        deconj_ba_abbrev(roots, chars, steps.clone().with(Step::BaAbbrev));
+    }
     if let Some((chars, ['ま', 'せ', 'ん'])) = chars.split_last_chunk() {
         push_masu_root(chars, roots, steps.clone().with(Step::Masen));
         return;
@@ -60,6 +345,29 @@ fn deconj_expr(chars: &[char], roots: &mut Vec<Root>, steps: Vec<Step>) {
             steps.clone().with(Step::Imperative).with(Step::Toku),
         );
     }
+    if let Some((chars, ['く', 'だ', 'さ', 'い'])) = chars.split_last_chunk() {
+        deconj_honorific(roots, chars, steps.clone().with(Step::OKudasai));
+    }
+    // くれる has an irregular imperative
+    if let Some((chars, ['く', 'れ'])) = chars.split_last_chunk() {
+        deconj_te_aux_root(
+            roots,
+            chars,
+            &[],
+            steps.clone().with(Step::Imperative).with(Step::TeKureru),
+        );
+    }
+    if let Some(('い', stem)) = chars.split_last()
+        && is_aru_stem(stem)
+    {
+        let steps = steps.clone().with(Step::Imperative);
+        roots.push(Root {
+            text: stem.to_string(),
+            kind: RootKind::GodanAru,
+            steps: steps.clone(),
+        });
+        deconj_aux_root(roots, stem, RootKind::GodanAru, steps);
+    }
     let Some((last_ch, chars)) = chars.split_last() else {
         return;
     };
@@ -70,71 +378,50 @@ fn deconj_expr(chars: &[char], roots: &mut Vec<Root>, steps: Vec<Step>) {
         'た' => deconj_ta(roots, chars, steps),
         'だ' => deconj_da(roots, chars, steps),
         'い' => deconj_i(roots, chars, steps),
-        'さ' => roots.push(Root {
-            text: chars.to_string(),
-            kind: RootKind::IAdjective,
-            steps: steps.with(Step::Sa),
-        }),
-        'え' => roots.push(Root {
-            text: chars.to_string(),
-            kind: RootKind::GodanU,
-            steps: steps.with(Step::Imperative),
-        }),
+        'さ' => push_i_adjective_root(roots, chars, steps.with(Step::Sa)),
+        'え' => push_imperative_root(roots, chars, RootKind::GodanU, steps),
         'う' => deconj_u(roots, chars, steps),
-        'く' => push_i_adjective_root(roots, chars, steps.with(Step::AdverbialKu)),
-        'ろ' => roots.push(Root {
-            text: chars.to_string(),
-            kind: RootKind::Ichidan,
-            steps: steps.with(Step::Imperative),
-        }),
-        'れ' => {
-            roots.push(Root {
-                text: chars.to_string(),
-                kind: RootKind::GodanRu,
-                steps: vec![Step::Imperative],
-            });
+        'く' => {
+            // Plain ていく
+            deconj_aux_root(roots, chars, RootKind::Iku, steps.clone());
+            push_i_adjective_root(roots, chars, steps.with(Step::AdverbialKu));
         }
+        'ろ' => push_imperative_root(roots, chars, RootKind::Ichidan, steps),
+        'れ' => push_imperative_root(roots, chars, RootKind::GodanRu, steps),
         'け' => {
-            roots.push(Root {
-                text: chars.to_string(),
-                kind: RootKind::GodanKu,
-                steps: vec![Step::Imperative],
-            });
-            roots.push(Root {
-                text: chars.to_string(),
-                kind: RootKind::Iku,
-                steps: vec![Step::Imperative],
-            });
+            push_imperative_root(roots, chars, RootKind::GodanKu, steps.clone());
+            push_imperative_root(roots, chars, RootKind::Iku, steps);
         }
-        'げ' => {
-            roots.push(Root {
-                text: chars.to_string(),
-                kind: RootKind::GodanGu,
-                steps: steps.clone().with(Step::Imperative),
-            });
-        }
-        'ね' => {
-            roots.push(Root {
-                text: chars.to_string(),
-                kind: RootKind::GodanNu,
-                steps: vec![Step::Imperative],
-            });
-        }
-        'め' => {
-            roots.push(Root {
-                text: chars.to_string(),
-                kind: RootKind::GodanMu,
-                steps: steps.clone().with(Step::Imperative),
-            });
-        }
-        'べ' => {
-            roots.push(Root {
-                text: chars.to_string(),
-                kind: RootKind::GodanBu,
-                steps: steps.clone().with(Step::Imperative),
-            });
+        'げ' => push_imperative_root(roots, chars, RootKind::GodanGu, steps),
+        'ね' => push_imperative_root(roots, chars, RootKind::GodanNu, steps),
+        'め' => push_imperative_root(roots, chars, RootKind::GodanMu, steps),
+        'べ' => push_imperative_root(roots, chars, RootKind::GodanBu, steps),
+        'る' => {
+            // Plain godan る auxiliaries (たがる, etc.)
+            deconj_aux_root(roots, chars, RootKind::GodanRu, steps.clone());
+            if is_aru_stem(chars) {
+                deconj_aux_root(roots, chars, RootKind::GodanAru, steps.clone());
+            }
+            if let Some(('あ', chars)) = chars.split_last() {
+                // Plain ある (and である)
+                roots.push(Root {
+                    text: chars.to_string(),
+                    kind: RootKind::Aru,
+                    steps: steps.clone(),
+                });
+            }
+            if let Some(('く', chars)) = chars.split_last() {
+                // Plain てくる
+                deconj_aux_root(roots, chars, RootKind::Kuru, steps.clone());
+            }
+            if let Some(('す', chars)) = chars.split_last() {
+                // Plain お～する
+                deconj_aux_root(roots, chars, RootKind::Suru, steps.clone());
+            }
+            push_ichidan_root(chars, roots, steps, false);
         }
-        'る' => push_ichidan_root(chars, roots, steps, false),
+        // Plain godan す auxiliaries (short causative, いたす)
+        'す' => deconj_aux_root(roots, chars, RootKind::GodanSu, steps),
         'ず' => deconj_zu(roots, chars, steps),
         'か' => deconj_ka(roots, chars, steps),
         'り' => deconj_ri(roots, chars, steps),
@@ -145,7 +432,9 @@ fn deconj_expr(chars: &[char], roots: &mut Vec<Root>, steps: Vec<Step>) {
         'せ' => deconj_se(roots, chars, steps),
         'き' => deconj_ki(roots, chars, steps),
         'み' => deconj_mi(roots, chars, steps),
-        'ぬ' => push_negative_root(chars, roots, steps.with(Step::Nu)),
+        'に' => push_na_adjective_root(roots, chars, steps.with(Step::Ni)),
+        'ぬ' => push_literary_negative_root(chars, roots, steps.with(Step::Nu)),
+        'ん' => push_literary_negative_root(chars, roots, steps.with(Step::N)),
         _ => {}
     }
 }
@@ -158,16 +447,50 @@ fn push_i_adjective_root(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>
         kind: RootKind::IAdjective,
         steps: steps.clone(),
     });
+    if let Some('よ') = chars.last() {
+        roots.push(Root {
+            text: chars.to_string(),
+            kind: RootKind::Yoi,
+            steps: steps.clone(),
+        });
+    }
+    deconj_i_adjective_aux(roots, chars, steps);
+}
+
+/// Auxiliaries that conjugate like い adjectives (ない, たい, てほしい, etc.)
+fn deconj_i_adjective_aux(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
+    if let Some((chars, ['て', 'ほ', 'し'])) = chars.split_last_chunk() {
+        push_te_root(roots, chars, steps.clone().with(Step::TeHoshii));
+    }
+    if let Some((chars, ['で', 'ほ', 'し'])) = chars.split_last_chunk() {
+        push_de_root(roots, chars, steps.clone().with(Step::TeHoshii));
+    }
+    if let Some((chars, ['か', 'も', 'し', 'れ', 'な'])) = chars.split_last_chunk() {
+        deconj_presumptive(roots, chars, steps.clone().with(Step::Kamoshirenai));
+    }
+    if let Some((chars, ['ら', 'し'])) = chars.split_last_chunk() {
+        deconj_presumptive(roots, chars, steps.clone().with(Step::Rashii));
+    }
     let Some((last, chars)) = chars.split_last() else {
         return;
     };
     match last {
-        'な' => push_negative_root(chars, roots, steps.with(Step::Nai)),
+        'な' => deconj_nai(roots, chars, steps),
         'た' => push_masu_root(chars, roots, steps.with(Step::Tai)),
         _ => {}
     }
 }
 
+/// Polite copula after an adjective, or a polite negative (～ませんでした)
+fn deconj_desu(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
+    debug!("deconj_desu: {chars:?}, {steps:?}");
+    push_na_adjective_root(roots, chars, steps.clone());
+    if let Some(('い', chars)) = chars.split_last() {
+        push_i_adjective_root(roots, chars, steps.clone());
+    }
+    deconj_conjugated(chars, roots, steps);
+}
+
 fn deconj_mi(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
     debug!("deconj_mi: {chars:?}, {steps:?}");
     // Godan mu verb stem
@@ -212,17 +535,302 @@ fn deconj_shi(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
     roots.push(Root {
         text: chars.to_string(),
         kind: RootKind::GodanSu,
-        steps: steps.with(Step::Stem),
+        steps: steps.clone().with(Step::Stem),
     });
+    deconj_aux_root(roots, chars, RootKind::GodanSu, steps.with(Step::Stem));
 }
 
 fn deconj_na(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
     debug!("deconj_na: {chars:?}, {steps:?}");
+    push_na_adjective_root(roots, chars, steps.with(Step::Na));
+}
+
+fn push_na_adjective_root(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
+    debug!("push_na_adjective_root: {chars:?}, {steps:?}");
     roots.push(Root {
         text: chars.to_string(),
         kind: RootKind::NaAdjective,
-        steps: steps.with(Step::Na),
+        steps: steps.clone(),
     });
+    if let Some((chars, ['そ', 'う'])) = chars.split_last_chunk() {
+        deconj_aux_stem(roots, chars, steps.clone().with(Step::Sou));
+    }
+    if let Some((chars, ['み', 'た', 'い'])) = chars.split_last_chunk() {
+        deconj_presumptive(roots, chars, steps.clone().with(Step::Mitai));
+    }
+    if let Some((chars, ['よ', 'う'])) = chars.split_last_chunk() {
+        deconj_presumptive(roots, chars, steps.with(Step::You));
+    }
+}
+
+/// Presumptive and evidential auxiliaries (だろう, みたい, etc.) attach to terminal forms,
+/// or directly to な adjectives
+fn deconj_presumptive(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
+    debug!("deconj_presumptive: {chars:?}, {steps:?}");
+    if steps.first() == Some(&Step::You) {
+        // しずかなようだ
+        if let Some(('な', stem)) = chars.split_last() {
+            push_na_adjective_root(roots, stem, steps.clone());
+        }
+    } else {
+        push_na_adjective_root(roots, chars, steps.clone());
+    }
+    deconj_terminal(chars, roots, steps);
+}
+
+/// Stem of a verb or adjective that auxiliaries like そう and すぎる attach to
+fn deconj_aux_stem(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
+    debug!("deconj_aux_stem: {chars:?}, {steps:?}");
+    push_masu_root(chars, roots, steps.clone());
+    push_i_adjective_root(roots, chars, steps.clone());
+    push_na_adjective_root(roots, chars, steps.clone());
+    // ない and いい have an irregular さ stem (なさそう, よさそう)
+    if let Some(('さ', chars)) = chars.split_last() {
+        match chars.split_last() {
+            Some(('な', chars)) => deconj_nai(roots, chars, steps),
+            Some(('よ', _)) => push_i_adjective_root(roots, chars, steps),
+            _ => {}
+        }
+    }
+}
+
+/// Roots ending in the stem of an auxiliary verb (e.g. the すぎ of たべすぎる)
+/// can be deconjugated further
+fn deconj_aux_root(roots: &mut Vec<Root>, chars: &[char], kind: RootKind, steps: Vec<Step>) {
+    debug!("deconj_aux_root: {chars:?}, {kind:?}, {steps:?}");
+    match kind {
+        RootKind::Ichidan => {
+            deconj_zuru_root(roots, chars, &steps);
+            deconj_te_aux_root(roots, chars, &['み'], steps.clone().with(Step::TeMiru));
+            deconj_te_aux_root(
+                roots,
+                chars,
+                &['く', 'れ'],
+                steps.clone().with(Step::TeKureru),
+            );
+            deconj_te_aux_root(
+                roots,
+                chars,
+                &['あ', 'げ'],
+                steps.clone().with(Step::TeAgeru),
+            );
+            if let Some((chars, ['す', 'ぎ'])) = chars.split_last_chunk() {
+                deconj_aux_stem(roots, chars, steps.with(Step::Sugiru));
+            }
+        }
+        RootKind::GodanRu => {
+            if let Some((chars, ['に', 'な'])) = chars.split_last_chunk() {
+                deconj_honorific(roots, chars, steps.clone().with(Step::ONiNaru));
+            }
+            deconj_te_aux_root(roots, chars, &['や'], steps.clone().with(Step::TeYaru));
+            if let Some((chars, ['た', 'が'])) = chars.split_last_chunk() {
+                push_masu_root(chars, roots, steps.clone().with(Step::Tagaru));
+            }
+            if let Some(('が', chars)) = chars.split_last() {
+                // たがる is its own step, so don't look for たい here
+                push_bare_i_adjective_root(roots, chars, steps.clone().with(Step::Garu));
+                push_na_adjective_root(roots, chars, steps.with(Step::Garu));
+            }
+        }
+        RootKind::GodanAru => deconj_te_aux_root(
+            roots,
+            chars,
+            &['く', 'だ', 'さ'],
+            steps.with(Step::TeKudasaru),
+        ),
+        RootKind::GodanSu => {
+            // Humble する
+            if let Some((chars, ['い', 'た'])) = chars.split_last_chunk() {
+                let steps = steps.clone().with(Step::Itasu);
+                roots.push(Root {
+                    text: chars.to_string(),
+                    kind: RootKind::Suru,
+                    steps: steps.clone(),
+                });
+                deconj_aux_root(roots, chars, RootKind::Suru, steps);
+            }
+            push_short_causative(steps, chars, roots);
+        }
+        RootKind::Suru => deconj_honorific(roots, chars, steps.with(Step::OSuru)),
+        RootKind::GodanKu | RootKind::Iku => {
+            if kind == RootKind::GodanKu {
+                deconj_te_aux_root(
+                    roots,
+                    chars,
+                    &['い', 'た', 'だ'],
+                    steps.clone().with(Step::TeItadaku),
+                );
+            }
+            deconj_te_aux_root(roots, chars, &['い'], steps.with(Step::TeIku));
+        }
+        RootKind::GodanU => {
+            deconj_te_aux_root(
+                roots,
+                chars,
+                &['し', 'ま'],
+                steps.clone().with(Step::TeShimau),
+            );
+            deconj_te_aux_root(
+                roots,
+                chars,
+                &['も', 'ら'],
+                steps.clone().with(Step::TeMorau),
+            );
+            // ちまう/じまう
+            match chars.split_last_chunk() {
+                Some((chars, ['ち', 'ま'])) => {
+                    push_te_root(roots, chars, steps.with(Step::Chimau))
+                }
+                Some((chars, ['じ', 'ま'])) => {
+                    push_de_root(roots, chars, steps.with(Step::Chimau))
+                }
+                _ => {}
+            }
+        }
+        RootKind::Kuru => deconj_te_aux_root(roots, chars, &[], steps.with(Step::TeKuru)),
+        _ => {}
+    }
+}
+
+/// ずる verbs share some of their forms with ichidan じる verbs, and use ぜ for the rest
+fn deconj_zuru_root(roots: &mut Vec<Root>, chars: &[char], steps: &[Step]) {
+    let zuru_possible = match chars.last() {
+        Some('じ') => matches!(
+            steps.first(),
+            Some(
+                Step::Te
+                    | Step::Ta
+                    | Step::Tara
+                    | Step::Tari
+                    | Step::Masu
+                    | Step::Masen
+                    | Step::Invitational
+                    | Step::Tai
+                    | Step::Volitional
+            )
+        ),
+        Some('ぜ') => matches!(
+            steps.first(),
+            Some(
+                Step::Nai
+                    | Step::Nakatta
+                    | Step::Naide
+                    | Step::Zu
+                    | Step::Zuni
+                    | Step::Nu
+                    | Step::N
+                    | Step::Passive
+                    | Step::Causative
+            )
+        ),
+        _ => false,
+    };
+    if zuru_possible {
+        roots.push(Root {
+            text: chars.init().to_string(),
+            kind: RootKind::Zuru,
+            steps: steps.to_vec(),
+        });
+    }
+}
+
+/// お/ご prefixed honorific and humble forms (おまちください, ごれんらくする, etc.)
+fn deconj_honorific(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
+    match chars.split_first() {
+        Some(('お', stem)) => push_masu_root(stem, roots, steps),
+        Some(('ご', stem)) => roots.push(Root {
+            text: stem.to_string(),
+            kind: RootKind::Suru,
+            steps,
+        }),
+        _ => {}
+    }
+}
+
+/// Auxiliary verbs that attach to the て form (てみる, てしまう, etc.)
+fn deconj_te_aux_root(roots: &mut Vec<Root>, chars: &[char], aux: &[char], steps: Vec<Step>) {
+    let Some(chars) = chars.strip_suffix(aux) else {
+        return;
+    };
+    match chars.split_last() {
+        Some(('て', chars)) => push_te_root(roots, chars, steps),
+        Some(('で', chars)) => push_de_root(roots, chars, steps),
+        _ => {}
+    }
+}
+
+/// Plain (dictionary) form of a verb or adjective, followed by an auxiliary like そうだ
+fn deconj_terminal(chars: &[char], roots: &mut Vec<Root>, steps: Vec<Step>) {
+    debug!("deconj_terminal: {chars:?}, {steps:?}");
+    push_verb_terminal_root(chars, roots, steps.clone());
+    if let Some(('い', stem)) = chars.split_last() {
+        push_bare_i_adjective_root(roots, stem, steps.clone());
+    }
+    // Ichidan and conjugated forms (た, ない, etc.)
+    deconj_conjugated(chars, roots, steps);
+}
+
+/// An い adjective root, without looking into it for auxiliaries like [`push_i_adjective_root`] does
+fn push_bare_i_adjective_root(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
+    roots.push(Root {
+        text: chars.to_string(),
+        kind: RootKind::IAdjective,
+        steps: steps.clone(),
+    });
+    if let Some('よ') = chars.last() {
+        roots.push(Root {
+            text: chars.to_string(),
+            kind: RootKind::Yoi,
+            steps,
+        });
+    }
+}
+
+/// Plain form of a godan verb, する or 来る
+fn push_verb_terminal_root(chars: &[char], roots: &mut Vec<Root>, steps: Vec<Step>) {
+    push_godan_terminal_root(chars, roots, steps.clone());
+    if let Some((stem, ['す', 'る'])) = chars.split_last_chunk() {
+        for kind in [RootKind::Suru, RootKind::SpecialSuru] {
+            roots.push(Root {
+                text: stem.to_string(),
+                kind,
+                steps: steps.clone(),
+            });
+        }
+    }
+    if let Some((stem, ['く', 'る'])) = chars.split_last_chunk() {
+        roots.push(Root {
+            text: stem.to_string(),
+            kind: RootKind::Kuru,
+            steps,
+        });
+    }
+}
+
+/// Plain form of a godan verb (かく, よむ)
+fn push_godan_terminal_root(chars: &[char], roots: &mut Vec<Root>, steps: Vec<Step>) {
+    if let Some((last, stem)) = chars.split_last() {
+        let kinds = match last {
+            'う' => &[RootKind::GodanU][..],
+            'く' => &[RootKind::GodanKu, RootKind::Iku],
+            'ぐ' => &[RootKind::GodanGu],
+            'す' => &[RootKind::GodanSu],
+            'つ' => &[RootKind::GodanTsu],
+            'ぬ' => &[RootKind::GodanNu],
+            'ぶ' => &[RootKind::GodanBu],
+            'む' => &[RootKind::GodanMu],
+            'る' if is_aru_stem(stem) => &[RootKind::GodanRu, RootKind::GodanAru],
+            'る' => &[RootKind::GodanRu],
+            _ => &[],
+        };
+        for &kind in kinds {
+            roots.push(Root {
+                text: stem.to_string(),
+                kind,
+                steps: steps.clone(),
+            });
+        }
+    }
 }
 
 fn deconj_ba(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
@@ -230,6 +838,37 @@ fn deconj_ba(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
     push_e_root(roots, chars, steps.with(Step::Ba), true);
 }
 
+/// Contracted conditional (すりゃ, いきゃ, なけりゃ), where the e row kana and ば merge
+fn deconj_ba_abbrev(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
+    debug!("deconj_ba_abbrev: {chars:?}, {steps:?}");
+    let Some((&last, stem)) = chars.split_last() else {
+        return;
+    };
+    match last {
+        'り' if stem.last() == Some(&'す') => {
+            for kind in [RootKind::Suru, RootKind::SpecialSuru] {
+                roots.push(Root {
+                    text: stem.init().to_string(),
+                    kind,
+                    steps: steps.clone(),
+                });
+            }
+        }
+        'り' if stem.last() == Some(&'け') => {
+            push_i_adjective_root(roots, stem.init(), steps.clone())
+        }
+        _ => {}
+    }
+    let e_row = match last {
+        'き' => 'け',
+        'り' => 'れ',
+        _ => return,
+    };
+    let mut e_root = stem.to_vec();
+    e_root.push(e_row);
+    push_e_root(roots, &e_root, steps, true);
+}
+
 // Potential and ba roots are different for ichidan. Shocking, I know.
 fn push_e_root(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>, ba: bool) {
     debug!("push_e_root: {chars:?}, {steps:?}");
@@ -237,17 +876,21 @@ fn push_e_root(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>, ba: bool
         return;
     };
     match last {
-        'え' => roots.push(Root {
-            text: chars.to_string(),
-            kind: RootKind::GodanU,
-            steps,
-        }),
+        'え' => {
+            roots.push(Root {
+                text: chars.to_string(),
+                kind: RootKind::GodanU,
+                steps: steps.clone(),
+            });
+            deconj_aux_root(roots, chars, RootKind::GodanU, steps);
+        }
         'け' => {
             roots.push(Root {
                 text: chars.to_string(),
                 kind: RootKind::GodanKu,
                 steps: steps.clone(),
             });
+            deconj_aux_root(roots, chars, RootKind::GodanKu, steps.clone());
             // Iku/yuku handling
             if matches!(chars.last(), Some('い' | 'ゆ')) {
                 roots.push(Root {
@@ -262,11 +905,14 @@ fn push_e_root(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>, ba: bool
             kind: RootKind::GodanGu,
             steps,
         }),
-        'せ' => roots.push(Root {
-            text: chars.to_string(),
-            kind: RootKind::GodanSu,
-            steps,
-        }),
+        'せ' => {
+            roots.push(Root {
+                text: chars.to_string(),
+                kind: RootKind::GodanSu,
+                steps: steps.clone(),
+            });
+            deconj_aux_root(roots, chars, RootKind::GodanSu, steps);
+        }
         'て' => roots.push(Root {
             text: chars.to_string(),
             kind: RootKind::GodanTsu,
@@ -288,11 +934,14 @@ fn push_e_root(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>, ba: bool
             steps,
         }),
         'れ' => {
-            roots.push(Root {
-                text: chars.to_string(),
-                kind: RootKind::GodanRu,
-                steps: steps.clone(),
-            });
+            push_godan_ru_root(roots, chars, steps.clone());
+            if ba && let Some(('ず', stem)) = chars.split_last() {
+                roots.push(Root {
+                    text: stem.to_string(),
+                    kind: RootKind::Zuru,
+                    steps: steps.clone(),
+                });
+            }
             if ba {
                 push_ichidan_root(chars, roots, steps, false);
             } else if let Some(('ら', chars)) = chars.split_last() {
@@ -305,6 +954,19 @@ fn push_e_root(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>, ba: bool
                         steps,
                     });
                 }
+            } else if let [Step::Potential, rest @ ..] = &steps[..]
+                && !matches!(rest.first(), Some(Step::Passive | Step::Causative))
+            {
+                // ら抜き言葉: たべれる, これる
+                let steps = rest.to_vec().with(Step::RanukiPotential);
+                push_ichidan_root(chars, roots, steps.clone(), false);
+                if let Some('こ') = chars.last() {
+                    roots.push(Root {
+                        text: chars.to_string(),
+                        kind: RootKind::Kuru,
+                        steps,
+                    });
+                }
             }
         }
         _ => {}
@@ -323,6 +985,7 @@ fn deconj_ra(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
         }
         'た' => push_ta_root(chars, roots, steps.with(Step::Tara)),
         'だ' => push_da_root(chars, roots, steps.with(Step::Tara)),
+        'な' => push_na_adjective_root(roots, chars, steps.with(Step::Nara)),
         _ => {}
     }
 }
@@ -352,7 +1015,7 @@ fn deconj_ka(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
 
 fn deconj_zu(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
     debug!("deconj_zu: {chars:?}, {steps:?}");
-    push_negative_root(chars, roots, steps.with(Step::Zu));
+    push_literary_negative_root(chars, roots, steps.with(Step::Zu));
 }
 
 fn push_i_cont_root(steps: Vec<Step>, chars: &[char], roots: &mut Vec<Root>) {
@@ -376,6 +1039,27 @@ fn push_causative(steps: Vec<Step>, chars: &[char], roots: &mut Vec<Root>) {
     push_other_negative_root(chars, roots, steps.with(Step::Causative));
 }
 
+/// Short causative (いかす, たべさす), which conjugates like a godan す verb
+fn push_short_causative(steps: Vec<Step>, chars: &[char], roots: &mut Vec<Root>) {
+    debug!("push_short_causative: {chars:?}, {steps:?}");
+    // いかさす isn't a thing
+    if steps.first() == Some(&Step::ShortCausative) {
+        return;
+    }
+    let steps = steps.with(Step::ShortCausative);
+    if let Some(('さ', stem)) = chars.split_last() {
+        push_ichidan_root(stem, roots, steps.clone(), true);
+        if let Some(('こ', stem)) = stem.split_last() {
+            roots.push(Root {
+                text: stem.to_string(),
+                kind: RootKind::Kuru,
+                steps: steps.clone(),
+            });
+        }
+    }
+    push_godan_negative_root(chars, roots, steps);
+}
+
 fn push_passive(steps: Vec<Step>, chars: &[char], roots: &mut Vec<Root>) {
     debug!("push_passive: {chars:?}, {steps:?}");
     match chars.last() {
@@ -387,7 +1071,12 @@ fn push_passive(steps: Vec<Step>, chars: &[char], roots: &mut Vec<Root>) {
                 false,
             );
         }
-        Some('さ') => push_causative(steps.clone().with(Step::Passive), chars.init(), roots),
+        // Only godan verbs shorten the causative passive (まわらされる)
+        Some('さ') => push_godan_negative_root(
+            chars.init(),
+            roots,
+            steps.clone().with(Step::Passive).with(Step::Causative),
+        ),
         _ => (),
     }
     push_other_negative_root(chars, roots, steps.with(Step::Passive));
@@ -400,19 +1089,25 @@ fn deconj_i(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
         kind: RootKind::GodanU,
         steps: steps.clone().with(Step::Stem),
     });
+    deconj_i_adjective_aux(roots, chars, steps.clone());
     let Some((last, chars)) = chars.split_last() else {
         return;
     };
     match last {
-        'な' => deconj_nai(roots, chars, steps),
         'さ' => deconj_sai(roots, chars, steps),
-        'こ' => roots.push(Root {
+        'こ' => push_imperative_root(roots, chars, RootKind::Kuru, steps),
+        _ => {}
+    }
+}
+
+/// Nothing conjugates off an imperative, except for particles (まてってば)
+fn push_imperative_root(roots: &mut Vec<Root>, chars: &[char], kind: RootKind, steps: Vec<Step>) {
+    if matches!(steps.first(), None | Some(Step::Tteba)) {
+        roots.push(Root {
             text: chars.to_string(),
-            kind: RootKind::Kuru,
+            kind,
             steps: steps.with(Step::Imperative),
-        }),
-        'た' => push_masu_root(chars, roots, steps.with(Step::Tai)),
-        _ => {}
+        });
     }
 }
 
@@ -425,12 +1120,224 @@ fn deconj_sai(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
 
 fn deconj_nai(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
     debug!("deconj_nai: {chars:?}, {steps:?}");
+    if let Some(('く', chars)) = chars.split_last() {
+        // い adjective negative
+        push_i_adjective_root(roots, chars, steps.clone().with(Step::Kunai));
+    }
+    // な adjective negative
+    if let Some((chars, ['じ', 'ゃ'])) = chars.split_last_chunk() {
+        push_na_adjective_root(roots, chars, steps.clone().with(Step::Janai));
+    }
+    if let Some((chars, ['で', 'は'])) = chars.split_last_chunk() {
+        push_na_adjective_root(roots, chars, steps.clone().with(Step::Dewanai));
+    }
     push_negative_root(chars, roots, steps.with(Step::Nai));
 }
 
+/// Obligation (なければならない, etc.) and prohibition (てはいけない, etc.) constructions.
+///
+/// Returns whether the expression was one of them.
+fn deconj_obligation(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) -> bool {
+    // An obligation is a negative conditional followed by a "must" part
+    let conditionals: [(&[char], Step); 4] = [
+        (&['な', 'け', 'れ', 'ば'], Step::Nakereba),
+        (&['な', 'く', 'て', 'は'], Step::NakuteWa),
+        (&['な', 'く', 'ち', 'ゃ'], Step::Nakucha),
+        (&['な', 'き', 'ゃ'], Step::Nakya),
+    ];
+    let musts: [(&[char], Step); 3] = [
+        (&['な', 'ら', 'な', 'い'], Step::Naranai),
+        (&['い', 'け', 'な', 'い'], Step::Ikenai),
+        (&['だ', 'め'], Step::Dame),
+    ];
+    let mut found = false;
+    for (must_suffix, must) in musts {
+        let Some(chars) = chars.strip_suffix(must_suffix) else {
+            continue;
+        };
+        for (cond_suffix, cond) in &conditionals {
+            if let Some(chars) = chars.strip_suffix(*cond_suffix) {
+                let steps = steps.clone().with(must.clone()).with(cond.clone());
+                push_obligation_root(roots, chars, steps);
+                found = true;
+            }
+        }
+    }
+    if found {
+        return true;
+    }
+    // Plain なくちゃ (なきゃ is handled by the caller)
+    if let Some(chars) = chars.strip_suffix(&['な', 'く', 'ち', 'ゃ']) {
+        push_obligation_root(roots, chars, steps.with(Step::Nakucha));
+        return true;
+    }
+    // Not exclusive, しねば is also 死ねば
+    if let Some((chars, ['ね', 'ば'])) = chars.split_last_chunk() {
+        push_literary_negative_root(chars, roots, steps.clone().with(Step::Neba));
+    }
+    if let Some(chars) = chars.strip_suffix(&['は', 'い', 'け', 'な', 'い']) {
+        deconj_te_aux_root(roots, chars, &[], steps.with(Step::TeWaIkenai));
+        return true;
+    }
+    let prohibitions: [(&[char], Step); 2] =
+        [(&['い', 'け', 'な', 'い'], Step::ChaIkenai), (&['だ', 'め'], Step::ChaDame)];
+    for (suffix, step) in prohibitions {
+        match chars
+            .strip_suffix(suffix)
+            .and_then(|chars| chars.split_last_chunk())
+        {
+            Some((chars, ['ち', 'ゃ'])) => push_te_root(roots, chars, steps.with(step)),
+            Some((chars, ['じ', 'ゃ'])) => push_de_root(roots, chars, steps.with(step)),
+            _ => continue,
+        }
+        return true;
+    }
+    false
+}
+
+/// Negative root of a verb or い adjective before an obligation (いかなければ, やさしくなくちゃ)
+fn push_obligation_root(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
+    // Only い adjectives take く here, so don't look for verb roots as well
+    if let Some(('く', chars)) = chars.split_last() {
+        push_i_adjective_root(roots, chars, steps);
+        return;
+    }
+    push_negative_root(chars, roots, steps);
+}
+
+/// Negative roots for ず, ぬ, ん, etc., where する becomes せ
+fn push_literary_negative_root(chars: &[char], roots: &mut Vec<Root>, steps: Vec<Step>) {
+    if let Some(('せ', stem)) = chars.split_last() {
+        for kind in [RootKind::Suru, RootKind::SpecialSuru] {
+            roots.push(Root {
+                text: stem.to_string(),
+                kind,
+                steps: steps.clone(),
+            });
+        }
+    }
+    let mut negative_roots = Vec::new();
+    push_negative_root(chars, &mut negative_roots, steps.clone());
+    // せず, not しず
+    if let Some(('し', stem)) = chars.split_last() {
+        let stem = stem.to_string();
+        negative_roots.retain(|root| {
+            !(matches!(root.kind, RootKind::Suru | RootKind::SpecialSuru)
+                && root.text == stem
+                && root.steps == steps)
+        });
+    }
+    roots.extend(negative_roots);
+}
+
+/// Negative volitional. Godan verbs take the dictionary form, the rest mostly the stem.
+fn deconj_mai(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
+    match chars.split_last() {
+        Some(('す', stem)) => {
+            for kind in [RootKind::Suru, RootKind::SpecialSuru] {
+                roots.push(Root {
+                    text: stem.to_string(),
+                    kind,
+                    steps: steps.clone(),
+                });
+            }
+        }
+        Some(('こ', stem)) => roots.push(Root {
+            text: stem.to_string(),
+            kind: RootKind::Kuru,
+            steps: steps.clone(),
+        }),
+        _ => {}
+    }
+    roots.ichidan(chars.to_string(), steps.clone());
+    // たべるまい, するまい and くるまい are also used
+    if let Some(('る', stem)) = chars.split_last() {
+        roots.ichidan(stem.to_string(), steps.clone());
+        match stem.split_last() {
+            Some(('す', stem)) => {
+                for kind in [RootKind::Suru, RootKind::SpecialSuru] {
+                    roots.push(Root {
+                        text: stem.to_string(),
+                        kind,
+                        steps: steps.clone(),
+                    });
+                }
+            }
+            Some(('く', stem)) => roots.push(Root {
+                text: stem.to_string(),
+                kind: RootKind::Kuru,
+                steps: steps.clone(),
+            }),
+            _ => {}
+        }
+    }
+    // Only godan verbs take the plain form, so the rest of `deconj_terminal` doesn't apply
+    push_godan_terminal_root(chars, roots, steps);
+}
+
+/// Slangy ねえ negative. わからない can also become わかんねえ.
+fn deconj_nee(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
+    if let Some(('ん', stem)) = chars.split_last() {
+        roots.push(Root {
+            text: stem.to_string(),
+            kind: RootKind::GodanRu,
+            steps: steps.clone(),
+        });
+    }
+    push_negative_root(chars, roots, steps);
+}
+
+/// Godan る root, plus the ある special class (いらっしゃる, etc.) if it looks like one
+fn push_godan_ru_root(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
+    roots.push(Root {
+        text: chars.to_string(),
+        kind: RootKind::GodanRu,
+        steps: steps.clone(),
+    });
+    deconj_aux_root(roots, chars, RootKind::GodanRu, steps.clone());
+    if is_aru_stem(chars) {
+        roots.push(Root {
+            text: chars.to_string(),
+            kind: RootKind::GodanAru,
+            steps: steps.clone(),
+        });
+        deconj_aux_root(roots, chars, RootKind::GodanAru, steps);
+    }
+}
+
+/// Whether this is the stem of an ある special class verb (いらっしゃ, おっしゃ, くださ, なさ, ござ)
+fn is_aru_stem(chars: &[char]) -> bool {
+    [
+        &['し', 'ゃ'][..],
+        &['く', 'だ', 'さ'],
+        &['下', 'さ'],
+        &['な', 'さ'],
+        &['ご', 'ざ'],
+    ]
+    .iter()
+    .any(|stem| chars.ends_with(stem))
+}
+
+/// 問う, 乞う and 請う have う音便 te and ta forms (問うて, 乞うた)
+fn is_u_onbin_stem_end(ch: char) -> bool {
+    matches!(ch, 'と' | 'こ' | '問' | '乞' | '請')
+}
+
 /// Push both godan and ichidan negative roots
 fn push_negative_root(chars: &[char], roots: &mut Vec<Root>, steps: Vec<Step>) {
     debug!("push_negative_root: : {chars:?}, {steps:?}");
+    // The negative of ある is just ない
+    if matches!(steps.first(), Some(Step::Nai | Step::Nakatta | Step::Naide)) {
+        if chars.is_empty() {
+            roots.push(Root {
+                text: String::new(),
+                kind: RootKind::Aru,
+                steps: steps.clone(),
+            });
+        }
+        // かいてない
+        deconj_te_aux_root(roots, chars, &[], steps.clone().with(Step::TeAru));
+    }
     push_other_negative_root(chars, roots, steps.clone());
     push_ichidan_root(chars, roots, steps, false);
 }
@@ -444,6 +1351,7 @@ fn push_ichidan_root(
     debug!("push_ichidan_root: {chars:?}, {steps:?}");
     // The whole expression itself can be ichidan
     roots.ichidan(chars.to_string(), steps.clone());
+    deconj_aux_root(roots, chars, RootKind::Ichidan, steps.clone());
     if suru_possible {
         // It can also indeed be suru/special suru
         roots.push(Root {
@@ -459,7 +1367,7 @@ fn push_ichidan_root(
     }
     // Then we see what else it could be
     // Try for potential, but only if we're not already in a potential situation
-    if steps.first() != Some(&Step::Potential) {
+    if !matches!(steps.first(), Some(Step::Potential | Step::RanukiPotential)) {
         push_e_root(roots, chars, steps.clone().with(Step::Potential), false);
     }
     debug!("push_ichidan_root (after e root push): {chars:?}, {steps:?}");
@@ -506,14 +1414,45 @@ fn push_ichidan_root(
 fn push_other_negative_root(chars: &[char], roots: &mut Vec<Root>, steps: Vec<Step>) {
     debug!("push_other_negative_root: {chars:?}, {steps:?}");
     match chars.last() {
-        Some('ら') => {
-            // Godan ru
+        Some('し') => {
+            // Suru verb... Technically not godan, but oh well...
+            roots.push(Root {
+                text: chars.init().to_string(),
+                kind: RootKind::Suru,
+                steps: steps.clone(),
+            });
             roots.push(Root {
                 text: chars.init().to_string(),
-                kind: RootKind::GodanRu,
+                kind: RootKind::SpecialSuru,
                 steps,
             });
         }
+        Some('こ') => {
+            if steps.starts_with(&[Step::Passive, Step::Potential])
+                || steps.starts_with(&[Step::Passive, Step::Passive])
+            {
+                debug!("これ is nonsense conjugation for 来る.");
+                return;
+            }
+            // Kuru verb... Also not godan
+            roots.push(Root {
+                text: chars.init().to_string(),
+                kind: RootKind::Kuru,
+                steps: steps.clone(),
+            });
+            deconj_aux_root(roots, chars.init(), RootKind::Kuru, steps);
+        }
+        Some('せ') => {
+            push_causative(steps, chars.init(), roots);
+        }
+        _ => push_godan_negative_root(chars, roots, steps),
+    }
+}
+
+/// The あ row negative root of godan verbs (かか, よま)
+fn push_godan_negative_root(chars: &[char], roots: &mut Vec<Root>, steps: Vec<Step>) {
+    match chars.last() {
+        Some('ら') => push_godan_ru_root(roots, chars.init(), steps),
         Some('な') => {
             // Godan nu
             roots.push(Root {
@@ -527,8 +1466,9 @@ fn push_other_negative_root(chars: &[char], roots: &mut Vec<Root>, steps: Vec<St
             roots.push(Root {
                 text: chars.init().to_string(),
                 kind: RootKind::GodanKu,
-                steps,
+                steps: steps.clone(),
             });
+            deconj_aux_root(roots, chars.init(), RootKind::GodanKu, steps);
         }
         Some('が') => {
             // Godan gu
@@ -551,16 +1491,26 @@ fn push_other_negative_root(chars: &[char], roots: &mut Vec<Root>, steps: Vec<St
             roots.push(Root {
                 text: chars.init().to_string(),
                 kind: RootKind::GodanU,
-                steps,
+                steps: steps.clone(),
             });
+            deconj_aux_root(roots, chars.init(), RootKind::GodanU, steps);
         }
         Some('さ') => {
-            // Godan su
-            roots.push(Root {
-                text: chars.init().to_string(),
-                kind: RootKind::GodanSu,
-                steps,
-            });
+            // Godan su, or the godan す alternate of a special suru verb (あいさない).
+            // The special suru verb itself only shares the passive.
+            let special_suru_kind = if steps.first() == Some(&Step::Passive) {
+                RootKind::SpecialSuru
+            } else {
+                RootKind::SpecialSuruGodan
+            };
+            for kind in [RootKind::GodanSu, special_suru_kind] {
+                roots.push(Root {
+                    text: chars.init().to_string(),
+                    kind,
+                    steps: steps.clone(),
+                });
+            }
+            deconj_aux_root(roots, chars.init(), RootKind::GodanSu, steps);
         }
         Some('た') => {
             // Godan su
@@ -578,51 +1528,38 @@ fn push_other_negative_root(chars: &[char], roots: &mut Vec<Root>, steps: Vec<St
                 steps,
             });
         }
-        Some('し') => {
-            // Suru verb... Technically not godan, but oh well...
-            roots.push(Root {
-                text: chars.init().to_string(),
-                kind: RootKind::Suru,
-                steps: steps.clone(),
-            });
-            roots.push(Root {
-                text: chars.init().to_string(),
-                kind: RootKind::SpecialSuru,
-                steps,
-            });
-        }
-        Some('こ') => {
-            if steps.starts_with(&[Step::Passive, Step::Potential])
-                || steps.starts_with(&[Step::Passive, Step::Passive])
-            {
-                debug!("これ is nonsense conjugation for 来る.");
-                return;
-            }
-            // Kuru verb... Also not godan
-            roots.push(Root {
-                text: chars.init().to_string(),
-                kind: RootKind::Kuru,
-                steps: steps.clone(),
-            });
-        }
-        Some('せ') => {
-            push_causative(steps, chars.init(), roots);
-        }
-        Some('く') => push_i_adjective_root(roots, chars.init(), steps.with(Step::AdverbialKu)),
         _ => {}
     }
 }
 
 fn deconj_te(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
     debug!("deconj_te: {chars:?}, {steps:?}");
-    roots.push(Root {
-        text: chars.to_string(),
-        kind: RootKind::GodanTsu,
-        steps: steps.clone().with(Step::Imperative),
-    });
+    push_imperative_root(roots, chars, RootKind::GodanTsu, steps.clone());
+    if let Some(('く', chars)) = chars.split_last() {
+        // い adjective て
+        push_i_adjective_root(roots, chars, steps.clone().with(Step::Te));
+    }
     push_te_root(roots, chars, steps.with(Step::Te));
 }
 
+/// Connectives following the て form of a verb (てから)
+fn deconj_te_connective(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
+    match chars.split_last() {
+        Some(('て', chars)) => push_te_root(roots, chars, steps.with(Step::Te)),
+        Some(('で', chars)) => push_de_root(roots, chars, steps.with(Step::Te)),
+        _ => {}
+    }
+}
+
+/// Connectives following the た form (たあと, たまま, etc.)
+fn deconj_ta_connective(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
+    match chars.split_last() {
+        Some(('た', chars)) => deconj_ta(roots, chars, steps),
+        Some(('だ', chars)) => deconj_da(roots, chars, steps),
+        _ => {}
+    }
+}
+
 fn push_te_root(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
     debug!("push_te_root: {chars:?}, {steps:?}");
     // Anything can be ichidan て root
@@ -631,18 +1568,31 @@ fn push_te_root(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
         kind: RootKind::Ichidan,
         steps: steps.clone(),
     });
+    deconj_aux_root(roots, chars, RootKind::Ichidan, steps.clone());
     let Some((last, chars)) = chars.split_last() else {
         return;
     };
     let kinds = match last {
+        'っ' if is_aru_stem(chars) => &[
+            RootKind::GodanRu,
+            RootKind::GodanAru,
+            RootKind::GodanTsu,
+            RootKind::GodanU,
+            RootKind::Iku,
+        ],
         'っ' => &[RootKind::GodanRu, RootKind::GodanTsu, RootKind::GodanU, RootKind::Iku][..],
         'い' => &[RootKind::GodanKu],
+        'う' if chars.last().is_some_and(|&ch| is_u_onbin_stem_end(ch)) => {
+            &[RootKind::GodanUOnbin]
+        }
         'し' => &[RootKind::GodanSu, RootKind::Suru, RootKind::SpecialSuru],
         'れ' => {
             push_passive(steps.clone(), chars, roots);
             return;
         }
-        'き' if chars.is_empty() => &[RootKind::Kuru],
+        'き' if chars.is_empty() || matches!(chars.last(), Some('て' | 'で')) => {
+            &[RootKind::Kuru]
+        }
         _ => return,
     };
     for &kind in kinds {
@@ -651,6 +1601,7 @@ fn push_te_root(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
             kind,
             steps: steps.clone(),
         });
+        deconj_aux_root(roots, chars, kind, steps.clone());
     }
 }
 
@@ -660,6 +1611,8 @@ fn deconj_de(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
         push_negative_root(&chars[..chars.len() - 2], roots, steps.with(Step::Naide));
         return;
     }
+    // な adjective て
+    push_na_adjective_root(roots, chars, steps.clone().with(Step::Te));
     push_de_root(roots, chars, steps.with(Step::Te));
 }
 
@@ -710,15 +1663,15 @@ fn deconj_ta(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
             }
             if let Some(('か', chars)) = chars.split_last() {
                 debug!("かった... い adjective past");
-                roots.push(Root {
-                    text: chars.to_string(),
-                    kind: RootKind::IAdjective,
-                    steps: steps.clone().with(Step::Katta),
-                });
+                push_i_adjective_root(roots, chars, steps.clone().with(Step::Katta));
                 if let Some('な') = chars.last() {
-                    push_negative_root(chars.init(), roots, steps.with(Step::Nakatta));
+                    push_negative_root(chars.init(), roots, steps.clone().with(Step::Nakatta));
                 }
             }
+            if let Some(('だ', chars)) = chars.split_last() {
+                debug!("だった... な adjective past");
+                push_na_adjective_root(roots, chars, steps.with(Step::Ta));
+            }
         }
         'い' => push_i_cont_root(steps.with(Step::Ta), chars, roots),
         'れ' => push_passive(steps.with(Step::Ta), chars, roots),
@@ -731,14 +1684,22 @@ fn deconj_ta(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
 fn push_ta_root(chars: &[char], roots: &mut Vec<Root>, steps: Vec<Step>) {
     debug!("push_ta_root: {chars:?}, {steps:?}");
     push_e_root(roots, chars, steps.clone().with(Step::Potential), false);
+    // い adjective かった (plain past is already handled as Katta by deconj_ta,
+    // and the concessive is くたって)
+    if let Some((chars, ['か', 'っ'])) = chars.split_last_chunk()
+        && !matches!(steps.first(), Some(Step::Ta | Step::Tatte))
+    {
+        push_i_adjective_root(roots, chars, steps.clone());
+    }
+    // な adjective だった
+    if let Some((chars, ['だ', 'っ'])) = chars.split_last_chunk()
+        && !matches!(steps.first(), Some(Step::Ta | Step::Tatte))
+    {
+        push_na_adjective_root(roots, chars, steps.clone());
+    }
     match chars.last() {
         Some('っ') => {
-            // Godan ru
-            roots.push(Root {
-                text: chars.init().to_string(),
-                kind: RootKind::GodanRu,
-                steps: steps.clone(),
-            });
+            push_godan_ru_root(roots, chars.init(), steps.clone());
             // Godan tsu
             roots.push(Root {
                 text: chars.init().to_string(),
@@ -751,27 +1712,47 @@ fn push_ta_root(chars: &[char], roots: &mut Vec<Root>, steps: Vec<Step>) {
                 kind: RootKind::GodanU,
                 steps: steps.clone(),
             });
+            deconj_aux_root(roots, chars.init(), RootKind::GodanU, steps.clone());
             // iku
             roots.push(Root {
                 text: chars.init().to_string(),
                 kind: RootKind::Iku,
-                steps,
+                steps: steps.clone(),
             });
+            deconj_aux_root(roots, chars.init(), RootKind::Iku, steps);
         }
         Some('し') => {
             if let Some(('ま', chars)) = chars.init().split_last() {
                 push_masu_root(chars, roots, steps.clone().with(Step::Masu))
             }
+            if let Some(('で', chars)) = chars.init().split_last() {
+                deconj_desu(roots, chars, steps.clone().with(Step::Desu))
+            }
             roots.push(Root {
                 text: chars.init().to_string(),
                 kind: RootKind::GodanSu,
-                steps,
+                steps: steps.clone(),
             });
+            deconj_aux_root(roots, chars.init(), RootKind::GodanSu, steps);
         }
         Some('い') => {
             roots.push(Root {
                 text: chars.init().to_string(),
                 kind: RootKind::GodanKu,
+                steps: steps.clone(),
+            });
+            deconj_aux_root(roots, chars.init(), RootKind::GodanKu, steps);
+        }
+        // 問うた, 乞うた
+        Some('う')
+            if chars
+                .init()
+                .last()
+                .is_some_and(|&ch| is_u_onbin_stem_end(ch)) =>
+        {
+            roots.push(Root {
+                text: chars.init().to_string(),
+                kind: RootKind::GodanUOnbin,
                 steps,
             });
         }
@@ -779,21 +1760,24 @@ fn push_ta_root(chars: &[char], roots: &mut Vec<Root>, steps: Vec<Step>) {
             roots.push(Root {
                 text: chars.init().to_string(),
                 kind: RootKind::Kuru,
-                steps,
+                steps: steps.clone(),
             });
+            deconj_aux_root(roots, chars.init(), RootKind::Kuru, steps);
         }
         _ => {
             // Ichidan
             roots.push(Root {
                 text: chars.to_string(),
                 kind: RootKind::Ichidan,
-                steps,
+                steps: steps.clone(),
             });
+            deconj_aux_root(roots, chars, RootKind::Ichidan, steps);
         }
     }
 }
 
 fn deconj_da(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
+    push_na_adjective_root(roots, chars, steps.clone().with(Step::Da));
     push_da_root(chars, roots, steps.clone().with(Step::Ta));
     if let Some(('ん', init)) = chars.split_last() {
         deconj_expr(init, roots, steps.with(Step::Nda));
@@ -836,6 +1820,8 @@ fn push_da_root(chars: &[char], roots: &mut Vec<Root>, steps: Vec<Step>) {
 
 fn deconj_u(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
     debug!("deconj_u: {chars:?}, {steps:?}");
+    // Plain てしまう
+    deconj_aux_root(roots, chars, RootKind::GodanU, steps.clone());
     let Some((last, chars)) = chars.split_last() else {
         return;
     };
@@ -854,7 +1840,13 @@ fn deconj_u(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
         'ぼ' => &[RootKind::GodanBu],
         'も' => &[RootKind::GodanMu],
         'の' => &[RootKind::GodanNu],
-        'ろ' => &[RootKind::GodanRu],
+        'ろ' => {
+            if let Some(('か', chars)) = chars.split_last() {
+                // い adjective presumptive (かろう)
+                push_i_adjective_root(roots, chars, steps.clone().with(Step::Volitional));
+            }
+            &[RootKind::GodanRu]
+        }
         'そ' => &[RootKind::GodanSu],
         'と' => &[RootKind::GodanTsu],
         'こ' => &[RootKind::GodanKu, RootKind::Iku][..],
@@ -868,6 +1860,7 @@ fn deconj_u(roots: &mut Vec<Root>, chars: &[char], steps: Vec<Step>) {
             kind,
             steps: steps.clone().with(Step::Volitional),
         });
+        deconj_aux_root(roots, chars, kind, steps.clone().with(Step::Volitional));
     }
 }
 
@@ -887,14 +1880,23 @@ fn push_masu_root(chars: &[char], roots: &mut Vec<Root>, steps: Vec<Step>) {
         kind: RootKind::Ichidan,
         steps: steps.clone(),
     });
+    deconj_aux_root(roots, chars, RootKind::Ichidan, steps.clone());
     let Some((last, chars)) = chars.split_last() else {
         return;
     };
+    // でございます
+    if let [Step::Masu, rest @ ..] = &steps[..]
+        && let Some((stem, ['で', 'ご', 'ざ'])) = chars.split_last_chunk()
+        && last == &'い'
+    {
+        push_na_adjective_root(roots, stem, rest.to_vec().with(Step::Gozaimasu));
+    }
     let kinds = match last {
+        'い' if is_aru_stem(chars) => &[RootKind::GodanU, RootKind::GodanAru][..],
         'い' => &[RootKind::GodanU],
         'き' => &[RootKind::GodanKu, RootKind::Kuru, RootKind::Iku][..],
         'ぎ' => &[RootKind::GodanGu],
-        'し' => &[RootKind::GodanSu],
+        'し' => &[RootKind::GodanSu, RootKind::Suru, RootKind::SpecialSuru],
         'ち' => &[RootKind::GodanTsu],
         'に' => &[RootKind::GodanNu],
         'び' => &[RootKind::GodanBu],
@@ -902,12 +1904,13 @@ fn push_masu_root(chars: &[char], roots: &mut Vec<Root>, steps: Vec<Step>) {
         'り' => &[RootKind::GodanRu],
         _ => &[],
     };
-    for kind in kinds {
+    for &kind in kinds {
         roots.push(Root {
             text: chars.to_string(),
-            kind: *kind,
+            kind,
             steps: steps.clone(),
         });
+        deconj_aux_root(roots, chars, kind, steps.clone());
     }
 }
 
@@ -8,6 +8,6 @@ mod root;
 mod tests;
 
 pub use {
-    deconjugate::deconjugate,
+    deconjugate::{deconjugate, deconjugate_kansai},
     root::{Root, RootKind, Step},
 };
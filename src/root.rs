@@ -17,7 +17,13 @@ impl std::fmt::Debug for Root {
                 write!(f, " ➡ ")?;
             }
         }
-        write!(f, " =「{}{}」", self.text, self.conjugation_suffix())?;
+        write!(
+            f,
+            " =「{}{}{}」",
+            self.conjugation_prefix(),
+            self.text,
+            self.conjugation_suffix()
+        )?;
         Ok(())
     }
 }
@@ -30,29 +36,60 @@ pub enum RootKind {
     GodanMu,
     GodanNu,
     GodanRu,
+    /// Honorific godan る verbs with い masu stem and imperative
+    /// (いらっしゃる, おっしゃる, くださる, なさる, ござる)
+    GodanAru,
     GodanSu,
     GodanTsu,
     GodanU,
+    /// Godan う verbs with irregular う音便 te and ta forms (問う, 乞う, 請う)
+    GodanUOnbin,
     GodanGu,
     GodanKu,
     /// Irregular 行く
     Iku,
     /// Irregular 来る
     Kuru,
+    /// Irregular ある
+    ///
+    /// The root text doesn't include the あ, so ある itself has an empty root text,
+    /// and the copula である has で. The negative is just ない.
+    Aru,
     /// Suru verb
     ///
     /// Note that these might require special handling if you're using a dictionary,
     /// because some suru verbs are listed with the する suffix as dictionary form,
     /// others are listed without. You need to handle both cases.
     Suru,
-    /// Special suru verbs (they conjugate differently in some cases?)
+    /// Suru verbs with a single kanji stem (愛する, 察する)
+    ///
+    /// Many of these have a godan す alternate (愛す), which is where forms like
+    /// 愛さない come from. Those forms are emitted as [`RootKind::SpecialSuruGodan`],
+    /// and forms shared by both (愛される, 愛させる) as both kinds.
     ///
     /// Note that these might require special handling if you're using a dictionary,
     /// because some suru verbs are listed with the する suffix as dictionary form,
     /// others are listed without. You need to handle both cases.
     SpecialSuru,
+    /// Godan す alternate of a [`RootKind::SpecialSuru`] verb (愛す for 愛する)
+    ///
+    /// Conjugates like [`RootKind::GodanSu`], but [`Root::dict_strings`] also includes
+    /// the する headword. Deconjugation emits it next to [`RootKind::GodanSu`] for
+    /// forms only the godan verb has (愛さない).
+    SpecialSuruGodan,
+    /// Suru verbs ending in ずる (信ずる, 感ずる)
+    ///
+    /// Most of these also have an ichidan じる alternate (信じる), which shares the
+    /// て, た and ます forms. See [`Root::dict_strings`] for looking up both spellings.
+    Zuru,
     IAdjective,
     NaAdjective,
+    /// Irregular いい/よい adjective
+    ///
+    /// The root text includes the よ (e.g. かっこよ for かっこいい), because
+    /// every form except the plain one is built on よ.
+    /// See [`Root::dict_strings`] for looking it up under both spellings.
+    Yoi,
 }
 
 #[cfg(feature = "labels")]
@@ -65,17 +102,23 @@ impl RootKind {
             RootKind::GodanMu => "む",
             RootKind::GodanNu => "ぬ",
             RootKind::GodanRu => "godan る",
+            RootKind::GodanAru => "godan る (ある special)",
             RootKind::GodanSu => "す",
             RootKind::GodanTsu => "つ",
             RootKind::GodanU => "う",
+            RootKind::GodanUOnbin => "う (うて/うた)",
             RootKind::GodanGu => "ぐ",
             RootKind::GodanKu => "く",
             RootKind::Iku => "行く",
             RootKind::Kuru => "来る",
+            RootKind::Aru => "ある",
             RootKind::Suru => "する",
             RootKind::SpecialSuru => "する (special)",
+            RootKind::SpecialSuruGodan => "す (special する)",
+            RootKind::Zuru => "ずる",
             RootKind::IAdjective => "い adjective",
             RootKind::NaAdjective => "な adjective",
+            RootKind::Yoi => "いい/よい",
         }
     }
 }
@@ -151,6 +194,150 @@ pub enum Step {
     TeOku,
     /// TeOku abbreviation
     Toku,
+    /// い adjective negative (くない)
+    Kunai,
+    /// な adjective copula (だ)
+    Da,
+    /// な adjective negative (じゃない)
+    Janai,
+    /// な adjective formal negative (ではない)
+    Dewanai,
+    /// な adjective conditional (なら)
+    Nara,
+    /// な adjective adverbial (に)
+    Ni,
+    /// Polite copula (です)
+    Desu,
+    /// い adjective polite negative (くありません)
+    KuArimasen,
+    /// な adjective polite negative (じゃありません)
+    JaArimasen,
+    /// な adjective formal polite negative (ではありません)
+    DewaArimasen,
+    /// Looks like... (そう after a stem)
+    Sou,
+    /// I heard that... (そうだ after a plain form)
+    SouDa,
+    /// Too much (すぎる)
+    Sugiru,
+    /// Third person want to ... (たがる)
+    Tagaru,
+    /// Show signs of an emotion (がる after an adjective)
+    Garu,
+    /// Want somebody to ... (てほしい)
+    TeHoshii,
+    /// Try doing ... (てみる)
+    TeMiru,
+    /// Has been done (てある)
+    TeAru,
+    /// て + くる (start to, come to, etc.)
+    TeKuru,
+    /// て + いく (full form of Teku)
+    TeIku,
+    /// Do completely, or regrettably (てしまう)
+    TeShimau,
+    /// Somebody does ... for me (てくれる)
+    TeKureru,
+    /// Have somebody do ... (てもらう)
+    TeMorau,
+    /// Do ... for somebody (てあげる)
+    TeAgeru,
+    /// Do ... for somebody of lower standing (てやる)
+    TeYaru,
+    /// Polite てくれる (てくださる)
+    TeKudasaru,
+    /// Polite てもらう (ていただく)
+    TeItadaku,
+    /// If not, as the first half of an obligation (なければならない)
+    Nakereba,
+    /// If not, as the first half of an obligation (なくてはいけない)
+    NakuteWa,
+    /// Must do, colloquial (なくちゃ)
+    Nakucha,
+    /// Second half of an obligation (ならない)
+    Naranai,
+    /// Second half of an obligation (いけない)
+    Ikenai,
+    /// Second half of an obligation, colloquial (だめ)
+    Dame,
+    /// Must do, literary (ねば)
+    Neba,
+    /// Must not do (てはいけない)
+    TeWaIkenai,
+    /// Must not do, colloquial (ちゃいけない)
+    ChaIkenai,
+    /// Must not do, colloquial (ちゃだめ)
+    ChaDame,
+    /// Even if (ても/でも)
+    Temo,
+    /// Even if, colloquial (たって/だって)
+    Tatte,
+    /// Colloquial negative (ん)
+    N,
+    /// Without doing (ずに)
+    Zuni,
+    /// Negative volitional (まい)
+    Mai,
+    /// Slangy negative (ねえ/ねー)
+    Nee,
+    /// Presumptive (だろう)
+    Darou,
+    /// Polite presumptive (でしょう)
+    Deshou,
+    /// Might be (かもしれない)
+    Kamoshirenai,
+    /// Seems like, colloquial (みたい)
+    Mitai,
+    /// Seems like (ようだ)
+    You,
+    /// Seems like, apparently (らしい)
+    Rashii,
+    /// Honorific お～になる
+    ONiNaru,
+    /// Humble お～する
+    OSuru,
+    /// Honorific request お～ください
+    OKudasai,
+    /// Humble する (いたす)
+    Itasu,
+    /// Polite copula (でございます)
+    Gozaimasu,
+    /// Colloquial potential without ら (たべれる, これる)
+    RanukiPotential,
+    /// Colloquial short causative (いかす, たべさす)
+    ShortCausative,
+    /// After doing (てから)
+    Kara,
+    /// After having done (たあと)
+    Ato,
+    /// Left as is (たまま)
+    Mama,
+    /// Just did (たばかり)
+    Bakari,
+    /// Just did, about to (たところ)
+    Tokoro,
+    /// Kansai negative (へん/ひん)
+    Hen,
+    /// Kansai honorific (はる)
+    Haru,
+    /// Kansai continuous (とる/どる)
+    Toru,
+    /// Kansai explanatory past (てん/でん)
+    Ten,
+    /// Kansai copula (や)
+    Ya,
+    /// Kansai う音便 te and ta forms of う verbs (ゆうて, こうた)
+    UOnbin,
+    /// Colloquial しまう (ちまう/じまう)
+    Chimau,
+    /// Colloquial negative of godan る verbs (わかんない)
+    Nnai,
+    /// Contracted conditional (すりゃ, いきゃ, なけりゃ)
+    BaAbbrev,
+    /// Colloquial ては (ちゃ/じゃ)
+    Cha,
+    /// Emphatic ってば
+    Tteba,
 }
 
 #[cfg(feature = "labels")]
@@ -195,6 +382,78 @@ impl Step {
             Step::Sa => "さ",
             Step::TeOku => "て + おく",
             Step::Toku => "とく (て + おく)",
+            Step::Kunai => "くない",
+            Step::Da => "だ",
+            Step::Janai => "じゃない",
+            Step::Dewanai => "ではない",
+            Step::Nara => "なら",
+            Step::Ni => "に",
+            Step::Desu => "です",
+            Step::KuArimasen => "くありません",
+            Step::JaArimasen => "じゃありません",
+            Step::DewaArimasen => "ではありません",
+            Step::Sou => "そう (appearance)",
+            Step::SouDa => "そうだ (hearsay)",
+            Step::Sugiru => "すぎる",
+            Step::Tagaru => "たがる",
+            Step::Garu => "がる",
+            Step::TeHoshii => "てほしい",
+            Step::TeMiru => "てみる",
+            Step::TeAru => "てある",
+            Step::TeKuru => "てくる",
+            Step::TeIku => "ていく",
+            Step::TeShimau => "てしまう",
+            Step::TeKureru => "てくれる",
+            Step::TeMorau => "てもらう",
+            Step::TeAgeru => "てあげる",
+            Step::TeYaru => "てやる",
+            Step::TeKudasaru => "てくださる",
+            Step::TeItadaku => "ていただく",
+            Step::Nakereba => "なければ",
+            Step::NakuteWa => "なくては",
+            Step::Nakucha => "なくちゃ",
+            Step::Naranai => "ならない",
+            Step::Ikenai => "いけない",
+            Step::Dame => "だめ",
+            Step::Neba => "ねば",
+            Step::TeWaIkenai => "てはいけない",
+            Step::ChaIkenai => "ちゃいけない",
+            Step::ChaDame => "ちゃだめ",
+            Step::Temo => "ても",
+            Step::Tatte => "たって",
+            Step::N => "ん (negative)",
+            Step::Zuni => "ずに",
+            Step::Mai => "まい",
+            Step::Nee => "ねえ",
+            Step::Darou => "だろう",
+            Step::Deshou => "でしょう",
+            Step::Kamoshirenai => "かもしれない",
+            Step::Mitai => "みたい",
+            Step::You => "よう",
+            Step::Rashii => "らしい",
+            Step::ONiNaru => "お～になる",
+            Step::OSuru => "お～する",
+            Step::OKudasai => "お～ください",
+            Step::Itasu => "いたす",
+            Step::Gozaimasu => "でございます",
+            Step::RanukiPotential => "potential (ら抜き)",
+            Step::ShortCausative => "causative (short)",
+            Step::Kara => "から",
+            Step::Ato => "あと",
+            Step::Mama => "まま",
+            Step::Bakari => "ばかり",
+            Step::Tokoro => "ところ",
+            Step::Hen => "へん (Kansai negative)",
+            Step::Haru => "はる (Kansai honorific)",
+            Step::Toru => "とる (Kansai continuous)",
+            Step::Ten => "てん (Kansai)",
+            Step::Ya => "や (Kansai copula)",
+            Step::UOnbin => "う音便 (Kansai)",
+            Step::Chimau => "ちまう",
+            Step::Nnai => "んない",
+            Step::BaAbbrev => "ば (contracted)",
+            Step::Cha => "ちゃ (ては)",
+            Step::Tteba => "ってば",
         }
     }
 }
@@ -204,6 +463,25 @@ impl Root {
     pub fn dict_string(&self) -> String {
         [&self.text, self.dict_suffix()].concat()
     }
+    /// All dictionary forms this root might be listed under
+    ///
+    /// Usually this is just [`Self::dict_string`], but some roots have alternative spellings
+    /// (e.g. よい can also be written いい, 信ずる can also be 信じる, 愛す can also be 愛する).
+    pub fn dict_strings(&self) -> Vec<String> {
+        let mut strings = vec![self.dict_string()];
+        if self.kind == RootKind::Yoi
+            && let Some(stem) = self.text.strip_suffix('よ')
+        {
+            strings.push([stem, "いい"].concat());
+        }
+        match self.kind {
+            RootKind::Zuru => strings.push([&self.text, "じる"].concat()),
+            RootKind::SpecialSuru => strings.push([&self.text, "す"].concat()),
+            RootKind::SpecialSuruGodan => strings.push([&self.text, "する"].concat()),
+            _ => {}
+        }
+        strings
+    }
     /// Dictionary suffix
     pub fn dict_suffix(&self) -> &'static str {
         match self.kind {
@@ -211,16 +489,18 @@ impl Root {
             RootKind::GodanBu => "ぶ",
             RootKind::GodanMu => "む",
             RootKind::GodanNu => "ぬ",
-            RootKind::GodanRu => "る",
-            RootKind::GodanSu => "す",
+            RootKind::GodanRu | RootKind::GodanAru => "る",
+            RootKind::GodanSu | RootKind::SpecialSuruGodan => "す",
             RootKind::GodanTsu => "つ",
-            RootKind::GodanU => "う",
+            RootKind::GodanU | RootKind::GodanUOnbin => "う",
             RootKind::GodanGu => "ぐ",
             RootKind::GodanKu => "く",
-            RootKind::IAdjective => "い",
+            RootKind::IAdjective | RootKind::Yoi => "い",
             RootKind::Iku => "く",
             RootKind::Kuru => "くる",
+            RootKind::Aru => "ある",
             RootKind::Suru | RootKind::SpecialSuru => "する",
+            RootKind::Zuru => "ずる",
             RootKind::NaAdjective => "",
         }
     }
@@ -231,15 +511,35 @@ impl Step {
     pub fn root_kind(&self) -> Option<RootKind> {
         Some(match self {
             Step::Te => RootKind::Ichidan,
-            Step::Nai => RootKind::IAdjective,
-            Step::Masu => RootKind::GodanSu,
+            Step::Nai | Step::Nnai => RootKind::IAdjective,
+            Step::Masu | Step::Desu | Step::Itasu | Step::Gozaimasu | Step::ShortCausative => {
+                RootKind::GodanSu
+            }
+            Step::OSuru => RootKind::Suru,
             Step::Continuous => RootKind::Ichidan,
             Step::ContRuAbbrev => RootKind::Ichidan,
-            Step::Potential => RootKind::Ichidan,
-            Step::Chau => RootKind::GodanU,
-            Step::Causative => RootKind::Ichidan,
-            Step::Tai => RootKind::IAdjective,
-            Step::Teku | Step::TeOku | Step::Toku => RootKind::GodanKu,
+            Step::Potential | Step::RanukiPotential => RootKind::Ichidan,
+            Step::Chau | Step::Chimau | Step::TeShimau | Step::TeMorau => RootKind::GodanU,
+            Step::TeKuru => RootKind::Kuru,
+            Step::TeIku => RootKind::Iku,
+            Step::Causative | Step::Sugiru | Step::TeMiru | Step::TeKureru | Step::TeAgeru => {
+                RootKind::Ichidan
+            }
+            Step::Tai
+            | Step::Kunai
+            | Step::Janai
+            | Step::Dewanai
+            | Step::TeHoshii
+            | Step::Kamoshirenai
+            | Step::Rashii => RootKind::IAdjective,
+            Step::Teku | Step::TeOku | Step::Toku | Step::TeItadaku => RootKind::GodanKu,
+            Step::Sou | Step::Mitai | Step::You => RootKind::NaAdjective,
+            Step::Tagaru | Step::Garu | Step::TeYaru | Step::ONiNaru | Step::Haru | Step::Toru => {
+                RootKind::GodanRu
+            }
+            Step::TeKudasaru => RootKind::GodanAru,
+            Step::UOnbin => RootKind::GodanUOnbin,
+            Step::TeAru => RootKind::Aru,
             _ => return None,
         })
     }
@@ -1,6 +1,6 @@
 use {
     super::{
-        deconjugate,
+        deconjugate, deconjugate_kansai,
         root::{Root, RootKind, Step},
     },
     owo_colors::{AnsiColors, OwoColorize},
@@ -96,7 +96,7 @@ fn test_deconjugate_positive() {
         // たい
         "つらぬきたい" => "つらぬ" GodanKu: Tai
         "あいたく" => "あ" GodanU: Tai AdverbialKu
-        "しにたくない" => "し" GodanNu: Tai AdverbialKu Nai
+        "しにたくない" => "し" GodanNu: Tai Kunai
         // Masen
         "すみません" => "す" GodanMu: Masen
         "かりません" => "かり" Ichidan: Masen
@@ -165,6 +165,44 @@ fn test_deconjugate_positive() {
         "とれる" => "と" GodanRu: Potential
         "よべる" => "よ" GodanBu: Potential
         "こられる" => "こ" Kuru: Potential
+        "たべれる" => "たべ" Ichidan: RanukiPotential
+        "たべれない" => "たべ" Ichidan: RanukiPotential Nai
+        "みれた" => "み" Ichidan: RanukiPotential Ta
+        "これた" => "こ" Kuru: RanukiPotential Ta
+        "これない" => "こ" Kuru: RanukiPotential Nai
+        // Short causative
+        "いかす" => "い" GodanKu: ShortCausative
+        "いかさない" => "い" GodanKu: ShortCausative Nai
+        "よまさない" => "よ" GodanMu: ShortCausative Nai
+        "たべさした" => "たべ" Ichidan: ShortCausative Ta
+        "たべさせば" => "たべ" Ichidan: ShortCausative Ba
+        "べんきょうさして" => "べんきょう" Suru: ShortCausative Te
+        "こさす" => "" Kuru: ShortCausative
+        // Connectives
+        "たべてから" => "たべ" Ichidan: Te Kara
+        "よんでから" => "よ" GodanMu: Te Kara
+        "いったあと" => "い" Iku: Ta Ato
+        "つけたまま" => "つけ" Ichidan: Ta Mama
+        "きたばかり" => "" Kuru: Ta Bakari
+        "よんだところ" => "よ" GodanMu: Ta Tokoro
+        "たかかったあと" => "たか" IAdjective: Katta Ato
+        // Colloquial contractions
+        "たべちまった" => "たべ" Ichidan: Chimau Ta
+        "よんじまう" => "よ" GodanMu: Chimau
+        "わかんない" => "わか" GodanRu: Nnai
+        "わかんなかった" => "わか" GodanRu: Nnai Katta
+        "すりゃ" => "" Suru: BaAbbrev
+        "いきゃ" => "い" Iku: BaAbbrev
+        "たべりゃ" => "たべ" Ichidan: BaAbbrev
+        "たかけりゃ" => "たか" IAdjective: BaAbbrev
+        "いかなけりゃ" => "い" GodanKu: Nai BaAbbrev
+        "たべちゃ" => "たべ" Ichidan: Cha
+        "よんじゃ" => "よ" GodanMu: Cha
+        "たかくちゃ" => "たか" IAdjective: Cha
+        "たべるってば" => "たべ" Ichidan: Tteba
+        "かけってば" => "か" GodanKu: Imperative Tteba
+        "かくってば" => "か" GodanKu: Tteba
+        "いったってば" => "い" Iku: Ta Tteba
         "ぬげる" => "ぬ" GodanGu: Potential
         "すすめる" => "すす" GodanMu: Potential
         "ゆける" => "ゆ" GodanKu: Potential
@@ -187,6 +225,26 @@ fn test_deconjugate_positive() {
         "はやく" => "はや" IAdjective: AdverbialKu
         // い past
         "つまらなかった" => "つまらな" IAdjective: Katta
+        // くない
+        "たかくない" => "たか" IAdjective: Kunai
+        "たかくなかった" => "たか" IAdjective: Kunai Katta
+        "やさしくなくて" => "やさし" IAdjective: Kunai Te
+        "たかくなければ" => "たか" IAdjective: Kunai Kereba
+        "たべたくない" => "たべ" Ichidan: Tai Kunai
+        // い adjective past, て, conditional, presumptive
+        "たかかった" => "たか" IAdjective: Katta
+        "たかくて" => "たか" IAdjective: Te
+        "たかかったら" => "たか" IAdjective: Tara
+        "たかかったり" => "たか" IAdjective: Tari
+        "たかかろう" => "たか" IAdjective: Volitional
+        "たべたかったら" => "たべ" Ichidan: Tai Tara
+        "いかなかったら" => "い" GodanKu: Nai Tara
+        // いい/よい
+        "よくない" => "よ" Yoi: Kunai
+        "よかった" => "よ" Yoi: Katta
+        "よければ" => "よ" Yoi: Kereba
+        "かっこよくて" => "かっこよ" Yoi: Te
+        "よさ" => "よ" Yoi: Sa
         // い stem
         "おいし" => "おいし" IAdjective:
         // い adjective archaic き
@@ -195,6 +253,93 @@ fn test_deconjugate_positive() {
         // な Adjectives
         "かすかな" => "かすか" NaAdjective: Na
         "にぎやかな" => "にぎやか" NaAdjective: Na
+        "しずかだ" => "しずか" NaAdjective: Da
+        "しずかだった" => "しずか" NaAdjective: Ta
+        "しずかで" => "しずか" NaAdjective: Te
+        "きれいじゃない" => "きれい" NaAdjective: Janai
+        "きれいじゃなかった" => "きれい" NaAdjective: Janai Katta
+        "きれいじゃなくて" => "きれい" NaAdjective: Janai Te
+        "しずかではない" => "しずか" NaAdjective: Dewanai
+        "しずかなら" => "しずか" NaAdjective: Nara
+        "しずかだったら" => "しずか" NaAdjective: Tara
+        "しずかに" => "しずか" NaAdjective: Ni
+        // Polite adjectives
+        "たかいです" => "たか" IAdjective: Desu
+        "たかかったです" => "たか" IAdjective: Katta Desu
+        "たかくないです" => "たか" IAdjective: Kunai Desu
+        "たかくありません" => "たか" IAdjective: KuArimasen
+        "たかくありませんでした" => "たか" IAdjective: KuArimasen Desu Ta
+        "しずかです" => "しずか" NaAdjective: Desu
+        "しずかでした" => "しずか" NaAdjective: Desu Ta
+        "しずかじゃありません" => "しずか" NaAdjective: JaArimasen
+        "しずかではありませんでした" => "しずか" NaAdjective: DewaArimasen Desu Ta
+        "たべませんでした" => "たべ" Ichidan: Masen Desu Ta
+        // そう
+        "たべそう" => "たべ" Ichidan: Sou
+        "ふりそう" => "ふ" GodanRu: Sou
+        "たかそう" => "たか" IAdjective: Sou
+        "よさそう" => "よ" Yoi: Sou
+        "たべなさそう" => "たべ" Ichidan: Nai Sou
+        "たかくなさそう" => "たか" IAdjective: Kunai Sou
+        "おいしそうな" => "おいし" IAdjective: Sou Na
+        "ふりそうだった" => "ふ" GodanRu: Sou Ta
+        "たべるそうだ" => "たべ" Ichidan: SouDa
+        "たかいそうだ" => "たか" IAdjective: SouDa
+        "いくそうだ" => "い" GodanKu: SouDa
+        "いったそうだ" => "い" Iku: Ta SouDa
+        // すぎる
+        "たべすぎた" => "たべ" Ichidan: Sugiru Ta
+        "たべすぎます" => "たべ" Ichidan: Sugiru Masu
+        "のみすぎない" => "の" GodanMu: Sugiru Nai
+        "たかすぎる" => "たか" IAdjective: Sugiru
+        "よさすぎる" => "よ" Yoi: Sugiru
+        "しずかすぎて" => "しずか" NaAdjective: Sugiru Te
+        // たがる, がる, てほしい
+        "たべたがっている" => "たべ" Ichidan: Tagaru Te Continuous
+        "いきたがる" => "い" GodanKu: Tagaru
+        "いきたがらない" => "い" GodanKu: Tagaru Nai
+        "さむがる" => "さむ" IAdjective: Garu
+        "さむがった" => "さむ" IAdjective: Garu Ta
+        "いやがらない" => "いや" NaAdjective: Garu Nai
+        "きてほしい" => "" Kuru: TeHoshii
+        "たべてほしかった" => "たべ" Ichidan: TeHoshii Katta
+        "よんでほしい" => "よ" GodanMu: TeHoshii
+        // て + auxiliary verbs
+        "たべてみた" => "たべ" Ichidan: TeMiru Ta
+        "よんでみる" => "よ" GodanMu: TeMiru
+        "かいてある" => "か" GodanKu: TeAru
+        "かいてあった" => "か" GodanKu: TeAru Ta
+        "かいてありません" => "か" GodanKu: TeAru Masen
+        "かいてない" => "か" GodanKu: TeAru Nai
+        "よんであれば" => "よ" GodanMu: TeAru Ba
+        "ふえてきた" => "ふえ" Ichidan: TeKuru Ta
+        "ふえてくる" => "ふえ" Ichidan: TeKuru
+        "ふえてこない" => "ふえ" Ichidan: TeKuru Nai
+        "ふえてきて" => "ふえ" Ichidan: TeKuru Te
+        "ふえてくるだろう" => "ふえ" Ichidan: TeKuru Darou
+        "もっていく" => "も" GodanTsu: TeIku
+        "もっていった" => "も" GodanTsu: TeIku Ta
+        "もっていかない" => "も" GodanTsu: TeIku Nai
+        "いってしまった" => "い" Iku: TeShimau Ta
+        "よんでしまう" => "よ" GodanMu: TeShimau
+        "わすれてしまわない" => "わすれ" Ichidan: TeShimau Nai
+        // Benefactives
+        "かってくれた" => "か" GodanU: TeKureru Ta
+        "まってくれ" => "ま" GodanTsu: TeKureru Imperative
+        "てつだってくれない" => "てつだ" GodanU: TeKureru Nai
+        "よんでもらった" => "よ" GodanMu: TeMorau Ta
+        "おしえてもらう" => "おしえ" Ichidan: TeMorau
+        "かってあげる" => "か" GodanU: TeAgeru
+        "かってあげた" => "か" GodanU: TeAgeru Ta
+        "ころしてやる" => "ころ" GodanSu: TeYaru
+        "おしえてくださった" => "おしえ" Ichidan: TeKudasaru Ta
+        "まってください" => "ま" GodanTsu: TeKudasaru Imperative
+        "おしえてくださいます" => "おしえ" Ichidan: TeKudasaru Masu
+        "おしえてくださいません" => "おしえ" Ichidan: TeKudasaru Masen
+        "おしえていただいた" => "おしえ" Ichidan: TeItadaku Ta
+        "よんでいただけない" => "よ" GodanMu: TeItadaku Potential Nai
+        "みせてもらえる" => "みせ" Ichidan: TeMorau Potential
+        // Plain い adjective
         // んだ
         "だいすきなんだ" => "だいすき" NaAdjective: Na Nda
         "はじめたんだ" => "はじめ" Ichidan: Ta Nda
@@ -203,6 +348,128 @@ fn test_deconjugate_positive() {
         "にげださなければ" => "にげだ" GodanSu: Nai Kereba
         // なきゃ
         "たちあがらなきゃ" => "たちあが" GodanRu: Nakya
+        // Obligation
+        "いかなければならない" => "い" GodanKu: Nakereba Naranai
+        "たべなければならない" => "たべ" Ichidan: Nakereba Naranai
+        "しなくてはいけない" => "" Suru: NakuteWa Ikenai
+        "いかなきゃいけない" => "い" GodanKu: Nakya Ikenai
+        "いかなければいけない" => "い" GodanKu: Nakereba Ikenai
+        "いかなくてはならない" => "い" GodanKu: NakuteWa Naranai
+        "いかなきゃならない" => "い" GodanKu: Nakya Naranai
+        "いかなくちゃならない" => "い" GodanKu: Nakucha Naranai
+        "いかなくちゃいけない" => "い" GodanKu: Nakucha Ikenai
+        "いかなくちゃだめ" => "い" GodanKu: Nakucha Dame
+        "たかくなければならない" => "たか" IAdjective: Nakereba Naranai
+        "かえらなくちゃ" => "かえ" GodanRu: Nakucha
+        "やさしくなくちゃ" => "やさし" IAdjective: Nakucha
+        "たかくなくてはいけない" => "たか" IAdjective: NakuteWa Ikenai
+        "よくなきゃだめ" => "よ" Yoi: Nakya Dame
+        "たたかわねば" => "たたか" GodanU: Neba
+        // Prohibition
+        "たべてはいけない" => "たべ" Ichidan: TeWaIkenai
+        "よんではいけない" => "よ" GodanMu: TeWaIkenai
+        "さわっちゃいけない" => "さわ" GodanRu: ChaIkenai
+        "しんじゃいけない" => "し" GodanNu: ChaIkenai
+        "わすれちゃだめ" => "わすれ" Ichidan: ChaDame
+        "あそんじゃだめ" => "あそ" GodanBu: ChaDame
+        // Concessive
+        "たべても" => "たべ" Ichidan: Temo
+        "よんでも" => "よ" GodanMu: Temo
+        "たかくても" => "たか" IAdjective: Temo
+        "しずかでも" => "しずか" NaAdjective: Temo
+        "いかなくても" => "い" GodanKu: Nai Temo
+        "しても" => "" Suru: Temo
+        "いったって" => "い" Iku: Tatte
+        "よんだって" => "よ" GodanMu: Tatte
+        "たかくたって" => "たか" IAdjective: Tatte
+        "しずかだって" => "しずか" NaAdjective: Tatte
+        "たべなくたって" => "たべ" Ichidan: Nai Tatte
+        // Negative variants
+        "わからん" => "わか" GodanRu: N
+        "たべん" => "たべ" Ichidan: N
+        "きにせん" => "きに" Suru: N
+        "たべずに" => "たべ" Ichidan: Zuni
+        "なにもせずに" => "なにも" Suru: Zuni
+        "せず" => "" Suru: Zu
+        "いくまい" => "い" Iku: Mai
+        "たべまい" => "たべ" Ichidan: Mai
+        "たべるまい" => "たべ" Ichidan: Mai
+        "すまい" => "" Suru: Mai
+        "するまい" => "" Suru: Mai
+        "こまい" => "" Kuru: Mai
+        "しらねえ" => "し" GodanRu: Nee
+        "わかんねー" => "わか" GodanRu: Nee
+        "たべねえ" => "たべ" Ichidan: Nee
+        // Presumptive and evidential
+        "たべるだろう" => "たべ" Ichidan: Darou
+        "いかないだろう" => "い" GodanKu: Nai Darou
+        "しずかだろう" => "しずか" NaAdjective: Darou
+        "たかいでしょう" => "たか" IAdjective: Deshou
+        "ふったかもしれない" => "ふ" GodanRu: Ta Kamoshirenai
+        "くるかもしれなかった" => "" Kuru: Kamoshirenai Katta
+        "いくみたいだ" => "い" Iku: Mitai Da
+        "いくみたい" => "い" Iku: Mitai
+        "しずかみたい" => "しずか" NaAdjective: Mitai
+        "あめがふるようだ" => "あめがふ" GodanRu: You Da
+        "しずかなようだ" => "しずか" NaAdjective: You Da
+        "かえったらしい" => "かえ" GodanRu: Ta Rashii
+        "べんきょうするらしい" => "べんきょう" Suru: Rashii
+        // ある special class
+        "いらっしゃいます" => "いらっしゃ" GodanAru: Masu
+        "いらっしゃい" => "いらっしゃ" GodanAru: Imperative
+        "いらっしゃった" => "いらっしゃ" GodanAru: Ta
+        "おっしゃらない" => "おっしゃ" GodanAru: Nai
+        "なさい" => "なさ" GodanAru: Imperative
+        "なさいました" => "なさ" GodanAru: Masu Ta
+        "ございます" => "ござ" GodanAru: Masu
+        "くだされば" => "くださ" GodanAru: Ba
+        // ある
+        "ない" => "" Aru: Nai
+        "なかった" => "" Aru: Nakatta
+        "あった" => "" Aru: Ta
+        "あります" => "" Aru: Masu
+        "ありません" => "" Aru: Masen
+        "ありませんでした" => "" Aru: Masen Desu Ta
+        "あれば" => "" Aru: Ba
+        "あらず" => "" Aru: Zu
+        "である" => "で" Aru:
+        "であった" => "で" Aru: Ta
+        "であります" => "で" Aru: Masu
+        "であろう" => "で" Aru: Volitional
+        // う音便
+        "とうて" => "と" GodanUOnbin: Te
+        "こうた" => "こ" GodanUOnbin: Ta
+        "問うた" => "問" GodanUOnbin: Ta
+        "こうている" => "こ" GodanUOnbin: Te Continuous
+        "とうたら" => "と" GodanUOnbin: Tara
+        // ずる
+        "しんぜず" => "しん" Zuru: Zu
+        "かんぜられる" => "かん" Zuru: Passive
+        "しんずれば" => "しん" Zuru: Ba
+        "しんじて" => "しん" Zuru: Te
+        "かんじた" => "かん" Zuru: Ta
+        "あんじます" => "あん" Zuru: Masu
+        "しんぜない" => "しん" Zuru: Nai
+        // Special suru godan す alternates
+        "あいさない" => "あい" GodanSu: Nai
+        "あいさない" => "あい" SpecialSuruGodan: Nai
+        "あいされる" => "あい" SpecialSuru: Passive
+        "あいされる" => "あい" GodanSu: Passive
+        "あいさせる" => "あい" SpecialSuru: Causative
+        "あいさせる" => "あい" GodanSu: Causative
+        // Keigo
+        "おまちください" => "ま" GodanTsu: OKudasai
+        "ごれんらくください" => "れんらく" Suru: OKudasai
+        "おかけになった" => "かけ" Ichidan: ONiNaru Ta
+        "おかきになった" => "か" GodanKu: ONiNaru Ta
+        "おかえりになります" => "かえ" GodanRu: ONiNaru Masu
+        "おたべになる" => "たべ" Ichidan: ONiNaru
+        "おまちする" => "ま" GodanTsu: OSuru
+        "おまちしました" => "ま" GodanTsu: OSuru Masu Ta
+        "ごれんらくいたします" => "れんらく" Suru: OSuru Itasu Masu
+        "れんらくいたします" => "れんらく" Suru: Itasu Masu
+        "しずかでございます" => "しずか" NaAdjective: Gozaimasu
+        "しずかでございました" => "しずか" NaAdjective: Gozaimasu Ta
         // さ
         "やさしさ" => "やさし" IAdjective: Sa
         "つよさ" => "つよ" IAdjective: Sa
@@ -230,6 +497,34 @@ fn test_deconjugate_negative() {
     }
     test_cases! {
         "いかない" => "いか" Suru: Nai
+        "たべてから" => "たべ" GodanTsu: Imperative Kara
+        "たべてん" => "たべ" GodanTsu: Imperative N
+        "たかい" => "たか" IAdjective:
+        "たべたがる" => "たべ" Ichidan: Tai Garu
+        "かけよう" => "か" GodanKu: Imperative
+        "かけよう" => "かけ" IAdjective: You
+        "たべるだろう" => "たべる" IAdjective: Darou
+        "たかくなさそう" => "たかく" GodanNu: ShortCausative ShortCausative Volitional
+        "いかささない" => "い" GodanKu: ShortCausative ShortCausative Nai
+        "のみゃ" => "の" GodanMu: BaAbbrev
+        "しずかりゃ" => "しずか" NaAdjective: BaAbbrev
+        "いい" => "よ" Yoi:
+        "いいです" => "よ" Yoi: Desu
+        "いかなきゃいけない" => "いかなきゃいけ" Ichidan: Nai
+        "いかなきゃいけない" => "いかなきゃいけな" IAdjective:
+        "あらない" => "" Aru: Nai
+        // あいしない, not あいさない
+        "あいさない" => "あい" SpecialSuru: Nai
+        "べんきょうしず" => "べんきょう" Suru: Zu
+        "おこす" => "お" Kuru: ShortCausative
+        "はなしす" => "はな" Suru: ShortCausative
+        "たかくす" => "たか" IAdjective: AdverbialKu ShortCausative
+        "たべまい" => "たべ" Ichidan: Stem Mai
+        // Must go, not must not go
+        "いかなくちゃいけない" => "いかなく" Ichidan: ChaIkenai
+        "あんない" => "" Aru: Nnai
+        // Kansai forms are opt-in
+        "わからへん" => "わか" GodanRu: Hen
     }
     // これ makes no sense for 来る
     assert!(
@@ -240,6 +535,55 @@ fn test_deconjugate_negative() {
     );
 }
 
+#[test]
+fn test_deconjugate_kansai() {
+    init_logger();
+    macro_rules! test_cases {
+        ($($kana:literal => $root:literal $kind:ident: $($step:ident)*)+) => {
+            $(
+                assert!(deconjugate_kansai($kana).contains(&Root{text: $root.into(), kind: RootKind::$kind, steps: vec![$(Step::$step),*]}));
+            )+
+        };
+    }
+    test_cases! {
+        // へん
+        "わからへん" => "わか" GodanRu: Hen
+        "わかれへん" => "わか" GodanRu: Hen
+        "たべへん" => "たべ" Ichidan: Hen
+        "かかへん" => "か" GodanKu: Hen
+        "せえへん" => "" Suru: Hen
+        "べんきょうしいひん" => "べんきょう" Suru: Hen
+        "けえへん" => "" Kuru: Hen
+        "あらへん" => "" Aru: Hen
+        "たかくあらへん" => "たか" IAdjective: Hen
+        "しずかやあらへん" => "しずか" NaAdjective: Hen
+        // はる
+        "いかはる" => "い" GodanKu: Haru
+        "たべはる" => "たべ" Ichidan: Haru
+        "しはった" => "" Suru: Haru Ta
+        "よまはらへん" => "よ" GodanMu: Haru Hen
+        // とる
+        "しとる" => "" Suru: Toru
+        "たべとった" => "たべ" Ichidan: Toru Ta
+        "よんどる" => "よ" GodanMu: Toru
+        "しとらへん" => "" Suru: Toru Hen
+        // てん
+        "ゆうてん" => "ゆ" GodanU: UOnbin Ten
+        "たべてん" => "たべ" Ichidan: Ten
+        "よんでん" => "よ" GodanMu: Ten
+        "しとってん" => "" Suru: Toru Ten
+        "こうた" => "こ" GodanU: UOnbin Ta
+        "ゆうて" => "ゆ" GodanU: UOnbin Te
+        // や
+        "しずかや" => "しずか" NaAdjective: Ya
+        "いくんや" => "い" Iku: Ya
+        "たべるんや" => "たべ" Ichidan: Ya
+        "たかいんや" => "たか" IAdjective: Ya
+        // Standard forms still work
+        "わからない" => "わか" GodanRu: Nai
+    }
+}
+
 #[test]
 fn test_conj() {
     init_logger();
@@ -286,9 +630,143 @@ fn test_conj() {
         GodanU: Nu => "わぬ"
         IAdjective: Ki => "き"
         NaAdjective: Na Nda => "なんだ"
+        NaAdjective: Da => "だ"
+        NaAdjective: Ta => "だった"
+        NaAdjective: Te => "で"
+        NaAdjective: Nai => "じゃない"
+        NaAdjective: Janai => "じゃない"
+        NaAdjective: Janai Katta => "じゃなかった"
+        NaAdjective: Dewanai => "ではない"
+        NaAdjective: Dewanai Te => "ではなくて"
+        NaAdjective: Nara => "なら"
+        NaAdjective: Tara => "だったら"
+        NaAdjective: Ni => "に"
+        IAdjective: Desu => "いです"
+        IAdjective: Katta Desu => "かったです"
+        IAdjective: Kunai Desu => "くないです"
+        IAdjective: KuArimasen => "くありません"
+        IAdjective: KuArimasen Desu Ta => "くありませんでした"
+        NaAdjective: Desu => "です"
+        NaAdjective: Desu Ta => "でした"
+        NaAdjective: JaArimasen => "じゃありません"
+        NaAdjective: DewaArimasen Desu Ta => "ではありませんでした"
+        Ichidan: Masen Desu Ta => "ませんでした"
+        Yoi: Kunai => "くない"
+        Yoi: Katta => "かった"
+        Yoi: Kereba => "ければ"
+        Yoi: Ba => "ければ"
+        IAdjective: Ba => "ければ"
+        Yoi: Imperative => "かれ"
+        IAdjective: Imperative => "かれ"
+        Yoi: Te => "くて"
+        Ichidan: Sou => "そう"
+        GodanRu: Sou Ta => "りそうだった"
+        IAdjective: Sou Na => "そうな"
+        Yoi: Sou => "さそう"
+        IAdjective: Kunai Sou => "くなさそう"
+        GodanKu: Nai Sou => "かなさそう"
+        Suru: Sou => "しそう"
+        Ichidan: SouDa => "るそうだ"
+        IAdjective: SouDa => "いそうだ"
+        NaAdjective: SouDa => "だそうだ"
+        GodanKu: Ta SouDa => "いたそうだ"
+        Ichidan: Sugiru Ta => "すぎた"
+        GodanMu: Sugiru Nai => "みすぎない"
+        GodanMu: Sugiru Masu => "みすぎます"
+        IAdjective: Sugiru => "すぎる"
+        Yoi: Sugiru => "さすぎる"
+        NaAdjective: Sugiru Te => "すぎて"
+        Ichidan: Tagaru Te Continuous => "たがっている"
+        GodanKu: Tagaru Nai => "きたがらない"
+        IAdjective: Garu => "がる"
+        IAdjective: Garu Ta => "がった"
+        NaAdjective: Garu Nai => "がらない"
+        Ichidan: TeHoshii => "てほしい"
+        Ichidan: TeHoshii Katta => "てほしかった"
+        GodanMu: TeHoshii => "んでほしい"
+        Ichidan: TeMiru Ta => "てみた"
+        GodanMu: TeMiru => "んでみる"
+        GodanKu: TeAru => "いてある"
+        GodanKu: TeAru Ta => "いてあった"
+        GodanKu: TeAru Nai => "いてない"
+        GodanKu: TeAru Masen => "いてありません"
+        GodanMu: TeAru Ba => "んであれば"
+        Ichidan: TeKuru => "てくる"
+        Ichidan: TeKuru Ta => "てきた"
+        Ichidan: TeKuru Nai => "てこない"
+        Ichidan: TeKuru Masu => "てきます"
+        Ichidan: TeKuru Ba => "てくれば"
+        GodanTsu: TeIku => "っていく"
+        GodanTsu: TeIku Ta => "っていった"
+        GodanTsu: TeIku Nai => "っていかない"
+        Iku: TeShimau Ta => "ってしまった"
+        GodanU: TeKureru Ta => "ってくれた"
+        GodanTsu: TeKureru Imperative => "ってくれ"
+        GodanMu: TeMorau Ta => "んでもらった"
+        GodanU: TeAgeru => "ってあげる"
+        GodanSu: TeYaru => "してやる"
+        Ichidan: TeKudasaru Ta => "てくださった"
+        GodanTsu: TeKudasaru Imperative => "ってください"
+        Ichidan: TeKudasaru Masu => "てくださいます"
+        Ichidan: TeKudasaru Masen => "てくださいません"
+        Ichidan: TeItadaku Ta => "ていただいた"
+        GodanMu: TeItadaku Potential Masu Ka => "んでいただけますか"
+        Ichidan: TeShimau Nai => "てしまわない"
         Ichidan: Ta Nda => "たんだ"
         Kuru: Nai => "ない" // Fine as long as it's 来る kanji... TODO: Find solution for kanjiless 出てこない
         Ichidan: Potential => "られる"
+        Ichidan: RanukiPotential => "れる"
+        Ichidan: RanukiPotential Nai => "れない"
+        Kuru: RanukiPotential Ta => "れた"
+        GodanKu: ShortCausative => "かす"
+        GodanMu: ShortCausative Nai => "まさない"
+        Ichidan: ShortCausative Ta => "さした"
+        Suru: ShortCausative Te => "さして"
+        Kuru: ShortCausative Masu => "さします"
+        Ichidan: Te Kara => "てから"
+        GodanMu: Te Kara => "んでから"
+        Iku: Ta Ato => "ったあと"
+        Ichidan: Ta Mama => "たまま"
+        GodanMu: Ta Bakari => "んだばかり"
+        Ichidan: Ta Tokoro => "たところ"
+        Ichidan: ShortCausative Passive => "さされる"
+        Ichidan: TeShimau Passive => "てしまわれる"
+        GodanRu: Hen => "らへん"
+        Ichidan: Hen => "へん"
+        Suru: Hen => "せえへん"
+        IAdjective: Hen => "くあらへん"
+        NaAdjective: Hen => "やあらへん"
+        Iku: Haru => "かはる"
+        Suru: Haru Ta => "しはった"
+        GodanMu: Haru Hen => "まはらへん"
+        Suru: Toru => "しとる"
+        GodanMu: Toru Ta => "んどった"
+        GodanU: Ten => "ってん"
+        GodanU: UOnbin Ten => "うてん"
+        GodanU: UOnbin Ta => "うた"
+        NaAdjective: Ya => "や"
+        Iku: Ya => "くんや"
+        Ichidan: Ya => "るんや"
+        Ichidan: Chimau Ta => "ちまった"
+        GodanMu: Chimau => "んじまう"
+        GodanRu: Nnai => "んない"
+        GodanRu: Nnai Katta => "んなかった"
+        Suru: BaAbbrev => "すりゃ"
+        Iku: BaAbbrev => "きゃ"
+        Ichidan: BaAbbrev => "りゃ"
+        GodanU: BaAbbrev => "や"
+        IAdjective: BaAbbrev => "けりゃ"
+        NaAdjective: BaAbbrev => "でありゃ"
+        NaAdjective: Volitional => "だろう"
+        NaAdjective: Imperative => "であれ"
+        NaAdjective: Ba => "であれば"
+        GodanKu: Nai BaAbbrev => "かなけりゃ"
+        Ichidan: Cha => "ちゃ"
+        GodanMu: Cha => "んじゃ"
+        Ichidan: Tteba => "るってば"
+        GodanKu: Tteba => "くってば"
+        IAdjective: Tteba => "いってば"
+        Iku: Ta Tteba => "ったってば"
         GodanGu: Imperative => "げ"
         Ichidan: Ba => "れば"
         GodanKu: Potential => "ける"
@@ -309,7 +787,124 @@ fn test_conj() {
         Kuru: Passive => "られる"
         Kuru: Causative Passive => "させられる"
         GodanRu: Nakya => "らなきゃ"
+        GodanKu: Nakereba Naranai => "かなければならない"
+        Suru: NakuteWa Ikenai => "しなくてはいけない"
+        GodanKu: Nakya Ikenai => "かなきゃいけない"
+        GodanKu: Nakucha Ikenai => "かなくちゃいけない"
+        IAdjective: Nakereba Naranai => "くなければならない"
+        GodanRu: Nakucha => "らなくちゃ"
+        IAdjective: Nakucha => "くなくちゃ"
+        GodanU: Neba => "わねば"
+        Ichidan: TeWaIkenai => "てはいけない"
+        GodanMu: TeWaIkenai => "んではいけない"
+        GodanRu: ChaIkenai => "っちゃいけない"
+        GodanNu: ChaIkenai => "んじゃいけない"
+        Ichidan: ChaDame => "ちゃだめ"
+        Ichidan: Temo => "ても"
+        Suru: Passive => "される"
+        SpecialSuru: Passive => "される"
+        SpecialSuruGodan: Nai => "さない"
+        SpecialSuruGodan: Volitional => "そう"
+        GodanSu: Passive => "される"
+        Zuru: Zu => "ぜず"
+        Zuru: Passive => "ぜられる"
+        Zuru: Ba => "ずれば"
+        Zuru: Te => "じて"
+        Zuru: Ta => "じた"
+        Zuru: Masu => "じます"
+        Zuru: Nai => "ぜない"
+        Zuru: Causative => "ぜさせる"
+        GodanUOnbin: Te => "うて"
+        GodanUOnbin: Ta => "うた"
+        GodanUOnbin: Tara => "うたら"
+        GodanUOnbin: Nai => "わない"
+        GodanUOnbin: Masu => "います"
+        Aru: Nai => "ない"
+        Aru: Nakatta => "なかった"
+        Aru: Ta => "あった"
+        Aru: Masu => "あります"
+        Aru: Masen Desu Ta => "ありませんでした"
+        Aru: Ba => "あれば"
+        Aru: Zu => "あらず"
+        Aru: Volitional => "あろう"
+        Aru: Te => "あって"
+        GodanAru: Masu => "います"
+        GodanAru: Imperative => "い"
+        GodanAru: Ta => "った"
+        GodanAru: Nai => "らない"
+        GodanAru: Ba => "れば"
+        GodanAru: Masen => "いません"
+        GodanTsu: OKudasai => "ちください"
+        Suru: OKudasai => "ください"
+        GodanKu: ONiNaru Ta => "きになった"
+        GodanRu: ONiNaru Masu => "りになります"
+        GodanTsu: OSuru => "ちする"
+        GodanTsu: OSuru Masu Ta => "ちしました"
+        Suru: OSuru Itasu Masu => "いたします"
+        Ichidan: TeKuru Darou => "てくるだろう"
+        Ichidan: TeKuru SouDa => "てくるそうだ"
+        Ichidan: TeKuru Rashii => "てくるらしい"
+        Ichidan: TeKuru You => "てくるよう"
+        Ichidan: TeKuru Mai => "てこまい"
+        Ichidan: TeKuru Nda => "てくるんだ"
+        Ichidan: TeKuru Tteba => "てくるってば"
+        Ichidan: TeKuru BaAbbrev => "てくりゃ"
+        Suru: Itasu Masu => "いたします"
+        GodanTsu: OSuru Itasu => "ちいたす"
+        NaAdjective: Gozaimasu => "でございます"
+        NaAdjective: Gozaimasu Ta => "でございました"
+        Ichidan: Darou => "るだろう"
+        GodanKu: Nai Darou => "かないだろう"
+        NaAdjective: Darou => "だろう"
+        IAdjective: Deshou => "いでしょう"
+        GodanRu: Ta Kamoshirenai => "ったかもしれない"
+        Kuru: Kamoshirenai Katta => "るかもしれなかった"
+        Iku: Mitai Da => "くみたいだ"
+        NaAdjective: Mitai => "みたい"
+        GodanRu: You Da => "るようだ"
+        NaAdjective: You Da => "なようだ"
+        GodanRu: Ta Rashii => "ったらしい"
+        Suru: Rashii => "するらしい"
+        GodanRu: N => "らん"
+        Suru: N => "せん"
+        Ichidan: Zuni => "ずに"
+        Suru: Zuni => "せずに"
+        Suru: Zu => "せず"
+        Suru: Nu => "せぬ"
+        Suru: Neba => "せねば"
+        Iku: Mai => "くまい"
+        Ichidan: Mai => "まい"
+        Suru: Mai => "すまい"
+        Kuru: Mai => "まい"
+        GodanRu: Nee => "らねえ"
+        Ichidan: Nee => "ねえ"
+        GodanMu: Temo => "んでも"
+        IAdjective: Temo => "くても"
+        NaAdjective: Temo => "でも"
+        GodanKu: Nai Temo => "かなくても"
+        Kuru: Temo => "ても"
+        Suru: Temo => "しても"
+        Iku: Tatte => "ったって"
+        GodanMu: Tatte => "んだって"
+        GodanKu: Tatte => "いたって"
+        IAdjective: Tatte => "くたって"
+        Yoi: Tatte => "くたって"
+        NaAdjective: Tatte => "だって"
+        Ichidan: Nai Tatte => "なくたって"
         IAdjective: Sa => "さ"
+        IAdjective: Kunai => "くない"
+        IAdjective: Kunai Katta => "くなかった"
+        IAdjective: Kunai Te => "くなくて"
+        IAdjective: Kunai Kereba => "くなければ"
+        IAdjective: Nai => "くない"
+        GodanKu: Tai Kunai => "きたくない"
+        IAdjective: Ta => "かった"
+        IAdjective: Te => "くて"
+        IAdjective: Tara => "かったら"
+        IAdjective: Tari => "かったり"
+        IAdjective: Volitional => "かろう"
+        GodanKu: Tai Tara => "きたかったら"
+        GodanKu: Nai Tara => "かなかったら"
         GodanU: Tai AdverbialKu => "いたく"
         GodanNu: Tai AdverbialKu Nai => "にたくない"
         GodanRu: Causative => "らせる"
@@ -330,6 +925,76 @@ fn test_conj() {
     }
 }
 
+/// Every root with the given text has to conjugate back into the input
+#[test]
+fn test_round_trip() {
+    init_logger();
+    macro_rules! test_cases {
+        ($deconjugate:ident: $($kana:literal => $root:literal)+) => {
+            $(
+                for root in $deconjugate($kana).into_iter().filter(|root| root.text == $root) {
+                    let conjugated =
+                        [root.conjugation_prefix(), &root.text, &root.conjugation_suffix()].concat();
+                    assert_eq!(conjugated, $kana, "{root:?}");
+                }
+            )+
+        };
+    }
+    test_cases! {
+        deconjugate:
+        "あいさない" => "あい"
+        "あいさず" => "あい"
+        "あいされる" => "あい"
+        "あいさせる" => "あい"
+        "はなさねえ" => "はな"
+        "たべまい" => "たべ"
+        "かくまい" => "か"
+        "べんきょうすまい" => "べんきょう"
+        "べんきょうせず" => "べんきょう"
+        "べんきょうせずに" => "べんきょう"
+        "たべさされる" => "たべ"
+        "たべてしまわれる" => "たべ"
+        "いかさない" => "い"
+        "かいてある" => "か"
+        "かいてない" => "か"
+        "かいてありません" => "か"
+        "たべるってば" => "たべ"
+        "かくってば" => "か"
+        "たかいってば" => "たか"
+        "いったってば" => "い"
+        "たかくなかった" => "たか"
+        "やさしくなくて" => "やさし"
+        "たくなさそう" => "た"
+        "やさしくなくちゃ" => "やさし"
+        "たかくなければならない" => "たか"
+        "たかくなくてはいけない" => "たか"
+        "よくなきゃだめ" => "よ"
+        "たべてから" => "たべ"
+        "よんでから" => "よ"
+        "してから" => ""
+        "かけよう" => "か"
+        "かけよう" => "かけ"
+        "たべるだろう" => "たべる"
+        "たかいらしい" => "たかい"
+        "かけってば" => "か"
+        "すりゃ" => ""
+        "いきゃ" => "い"
+        "たべりゃ" => "たべ"
+        "たかけりゃ" => "たか"
+        "いかなけりゃ" => "い"
+    }
+    test_cases! {
+        deconjugate_kansai:
+        "ゆうてん" => "ゆ"
+        "こうた" => "こ"
+        "たべるんや" => "たべ"
+        "いくんや" => "い"
+        "たかくあらへん" => "たか"
+        "しずかやあらへん" => "しずか"
+        "たべてん" => "たべ"
+    }
+}
+
 #[test]
 fn test_conj_special() {
     assert_eq!(
@@ -341,6 +1006,32 @@ fn test_conj_special() {
         .conjugation_suffix(),
         "ましょう"
     );
+    let conjugate =
+        |root: Root| [root.conjugation_prefix(), &root.text, &root.conjugation_suffix()].concat();
+    assert_eq!(
+        conjugate(Root {
+            text: "ま".into(),
+            kind: RootKind::GodanTsu,
+            steps: vec![Step::OKudasai]
+        }),
+        "おまちください"
+    );
+    assert_eq!(
+        conjugate(Root {
+            text: "れんらく".into(),
+            kind: RootKind::Suru,
+            steps: vec![Step::OSuru, Step::Itasu, Step::Masu]
+        }),
+        "ごれんらくいたします"
+    );
+    assert_eq!(
+        conjugate(Root {
+            text: "れんらく".into(),
+            kind: RootKind::Suru,
+            steps: vec![Step::Itasu, Step::Masu]
+        }),
+        "れんらくいたします"
+    );
 }
 
 #[test]
@@ -360,13 +1051,51 @@ fn test_dict() {
         "かり" Ichidan => "かりる"
         "こい" Suru => "こいする"
         "あい" SpecialSuru => "あいする"
+        "あい" SpecialSuruGodan => "あいす"
         "" Kuru => "くる"
         "でて" Kuru => "でてくる"
         "やさし" IAdjective => "やさしい"
         "行" GodanU => "行う"
+        "よ" Yoi => "よい"
+        "" Aru => "ある"
+        "で" Aru => "である"
+        "いらっしゃ" GodanAru => "いらっしゃる"
+        "と" GodanUOnbin => "とう"
+        "しん" Zuru => "しんずる"
     }
 }
 
+#[test]
+fn test_dict_strings() {
+    let root = |text: &str, kind| Root {
+        text: text.into(),
+        kind,
+        steps: vec![],
+    };
+    assert_eq!(
+        root("かっこよ", RootKind::Yoi).dict_strings(),
+        ["かっこよい", "かっこいい"]
+    );
+    assert_eq!(root("よ", RootKind::Yoi).dict_strings(), ["よい", "いい"]);
+    assert_eq!(
+        root("たか", RootKind::IAdjective).dict_strings(),
+        ["たかい"]
+    );
+    assert_eq!(
+        root("しん", RootKind::Zuru).dict_strings(),
+        ["しんずる", "しんじる"]
+    );
+    assert_eq!(
+        root("あい", RootKind::SpecialSuru).dict_strings(),
+        ["あいする", "あいす"]
+    );
+    assert_eq!(root("はな", RootKind::GodanSu).dict_strings(), ["はなす"]);
+    assert_eq!(
+        root("あい", RootKind::SpecialSuruGodan).dict_strings(),
+        ["あいす", "あいする"]
+    );
+}
+
 fn init_logger() {
     static INIT: std::sync::Once = std::sync::Once::new();
 